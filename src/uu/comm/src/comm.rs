@@ -291,6 +291,14 @@ fn comm(
                     .read_line(rb)
                     .map_err_context(|| filename2.maybe_quote().to_string())?;
                 total_col_3 += 1;
+                // Both files advanced; like GNU, check the freshly read lines
+                // in file order so that file 1's disorder is reported first.
+                if should_check_order
+                    && ((na != 0 && !checker1.verify_order(ra))
+                        || (nb != 0 && !checker2.verify_order(rb)))
+                {
+                    break;
+                }
             }
         }
 
@@ -45,6 +45,8 @@ cp-help-sparse = control creation of sparse files. See below
 cp-help-selinux = set SELinux security context of destination file to default type
 cp-help-context = like -Z, or if CTX is specified then set the SELinux or SMACK security context to CTX
 cp-help-progress = Display a progress bar. Note: this feature is not supported by GNU coreutils.
+cp-help-fsync = flush each copied file and its directory to disk. Note: this feature is not supported by GNU coreutils.
+cp-help-atomic = copy each regular file into a temporary file next to the destination and rename it into place. Note: this feature is not supported by GNU coreutils.
 cp-help-copy-contents = NotImplemented: copy contents of special files when recursive
 
 # Error messages
@@ -92,6 +94,9 @@ cp-error-cannot-create-regular-file = cannot create regular file { $path }
 cp-error-invalid-attribute = invalid attribute { $value }
 cp-error-failed-to-create-whole-tree = failed to create whole tree
 cp-error-failed-to-create-directory = Failed to create directory: { $error }
+cp-error-sync = error syncing { $path }
+cp-error-atomic-temp = cannot create temporary file in { $dir }
+cp-error-atomic-rename = cannot rename { $temp } to { $dest }
 cp-error-backup-format = cp: { $error }
   Try '{ $exec } --help' for more information.
 cp-error-setting-attributes = setting attributes for { $path }
@@ -109,6 +114,7 @@ cp-debug-enum-seek-hole-zeros = SEEK_HOLE + zeros
 
 # Warning messages
 cp-warning-source-specified-more-than-once = source { $file_type } { $source } specified more than once
+cp-warning-atomic-fallback = cannot rename into { $dest } across devices, overwriting it in place
 
 # Verbose and debug messages
 cp-debug-skipped = skipped { $path }
@@ -45,6 +45,8 @@ cp-help-sparse = contrôler la création de fichiers épars. Voir ci-dessous
 cp-help-selinux = définir le contexte de sécurité SELinux du fichier de destination au type par défaut
 cp-help-context = comme -Z, ou si CTX est spécifié, définir le contexte de sécurité SELinux ou SMACK à CTX
 cp-help-progress = Afficher une barre de progression. Note : cette fonctionnalité n'est pas prise en charge par GNU coreutils.
+cp-help-fsync = écrire sur le disque chaque fichier copié et son répertoire. Note : cette fonctionnalité n'est pas prise en charge par GNU coreutils.
+cp-help-atomic = copier chaque fichier standard dans un fichier temporaire à côté de la destination puis le renommer à sa place. Note : cette fonctionnalité n'est pas prise en charge par GNU coreutils.
 cp-help-copy-contents = Non implémenté : copier le contenu des fichiers spéciaux lors de la récursion
 
 # Messages d'erreur
@@ -92,6 +94,9 @@ cp-error-cannot-create-regular-file = impossible de créer le fichier standard {
 cp-error-invalid-attribute = attribut invalide { $value }
 cp-error-failed-to-create-whole-tree = échec de la création de l'arborescence complète
 cp-error-failed-to-create-directory = Échec de la création du répertoire : { $error }
+cp-error-sync = erreur de synchronisation de { $path }
+cp-error-atomic-temp = impossible de créer un fichier temporaire dans { $dir }
+cp-error-atomic-rename = impossible de renommer { $temp } en { $dest }
 cp-error-backup-format = cp : { $error }
   Tentez '{ $exec } --help' pour plus d'informations.
 
@@ -107,6 +112,7 @@ cp-debug-enum-seek-hole-zeros = SEEK_HOLE + zéros
 
 # Messages d'avertissement
 cp-warning-source-specified-more-than-once = { $file_type } source { $source } spécifié plus d'une fois
+cp-warning-atomic-fallback = impossible de renommer vers { $dest } entre périphériques, écrasement sur place
 
 # Messages verbeux et de débogage
 cp-debug-skipped = { $path } ignoré
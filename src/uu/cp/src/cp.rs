@@ -25,6 +25,7 @@ use filetime::FileTime;
 use indicatif::{ProgressBar, ProgressStyle};
 #[cfg(unix)]
 use nix::sys::stat::{Mode, SFlag, dev_t, mknod as nix_mknod, mode_t};
+use tempfile::{NamedTempFile, TempPath};
 use thiserror::Error;
 
 use platform::copy_on_write;
@@ -339,6 +340,10 @@ pub struct Options {
     pub update: UpdateMode,
     /// `--debug`
     pub debug: bool,
+    /// `--fsync`
+    pub fsync: bool,
+    /// `--atomic`
+    pub atomic: bool,
     /// `-v`, `--verbose`
     pub verbose: bool,
     /// `-g`, `--progress`
@@ -371,6 +376,8 @@ impl Default for Options {
             target_dir: None,
             update: UpdateMode::default(),
             debug: false,
+            fsync: false,
+            atomic: false,
             verbose: false,
             progress_bar: false,
             set_selinux_context: false,
@@ -495,6 +502,8 @@ mod options {
     pub const SYMBOLIC_LINK: &str = "symbolic-link";
     pub const TARGET_DIRECTORY: &str = "target-directory";
     pub const DEBUG: &str = "debug";
+    pub const FSYNC: &str = "fsync";
+    pub const ATOMIC: &str = "atomic";
     pub const VERBOSE: &str = "verbose";
 }
 
@@ -766,6 +775,18 @@ pub fn uu_app() -> Command {
                 .action(ArgAction::SetTrue)
                 .help(translate!("cp-help-progress")),
         )
+        .arg(
+            Arg::new(options::FSYNC)
+                .long(options::FSYNC)
+                .action(ArgAction::SetTrue)
+                .help(translate!("cp-help-fsync")),
+        )
+        .arg(
+            Arg::new(options::ATOMIC)
+                .long(options::ATOMIC)
+                .action(ArgAction::SetTrue)
+                .help(translate!("cp-help-atomic")),
+        )
         // TODO: implement the following args
         .arg(
             Arg::new(options::COPY_CONTENTS)
@@ -1225,6 +1246,8 @@ impl Options {
             parents: matches.get_flag(options::PARENTS),
             update: update_mode,
             debug: matches.get_flag(options::DEBUG),
+            fsync: matches.get_flag(options::FSYNC),
+            atomic: matches.get_flag(options::ATOMIC),
             verbose: matches.get_flag(options::VERBOSE) || matches.get_flag(options::DEBUG),
             strip_trailing_slashes: matches.get_flag(options::STRIP_TRAILING_SLASHES),
             reflink_mode: {
@@ -1446,7 +1469,12 @@ pub fn copy(sources: &[PathBuf], target: &Path, options: &Options) -> CopyResult
     for source in sources {
         let normalized_source = normalize_path(source);
         if options.backup == BackupMode::None && seen_sources.contains(&normalized_source) {
-            let file_type = if source.symlink_metadata()?.file_type().is_dir() {
+            // A failure to stat a repeated operand must not abort the
+            // remaining operands, so fall back to calling it a file.
+            let file_type = if source
+                .symlink_metadata()
+                .is_ok_and(|m| m.file_type().is_dir())
+            {
                 "directory"
             } else {
                 "file"
@@ -1935,9 +1963,18 @@ pub(crate) fn copy_attributes(
         if let Ok(context) = selinux::SecurityContext::of_path(source, false, false) {
             if let Some(context) = context {
                 if let Err(e) = context.set_for_path(dest, false, false) {
-                    return Err(CpError::Error(
-                        translate!("cp-error-selinux-set-context", "path" => dest.quote(), "error" => e),
-                    ));
+                    let message = translate!("cp-error-selinux-set-context", "path" => dest.quote(), "error" => e);
+                    // Keep the errno of a destination file system without label
+                    // support, so that `handle_preserve` can ignore it for -a.
+                    let errno = std::error::Error::source(&e)
+                        .and_then(|source| source.downcast_ref::<io::Error>())
+                        .and_then(io::Error::raw_os_error);
+                    return Err(match errno {
+                        Some(code @ libc::EOPNOTSUPP) => {
+                            CpError::IoErrContext(io::Error::from_raw_os_error(code), message)
+                        }
+                        _ => CpError::Error(message),
+                    });
                 }
             }
         } else {
@@ -2871,17 +2908,47 @@ fn copy_helper(
         // applying O_NOFOLLOW here only with `-P`.
         #[cfg(unix)]
         let nofollow = !options.dereference(source_in_command_line);
-        let copy_debug = copy_on_write(
-            source,
-            dest,
-            options.reflink_mode,
-            options.sparse_mode,
-            context,
-            #[cfg(unix)]
-            is_stream(source_metadata),
-            #[cfg(unix)]
-            nofollow,
-        )?;
+        let copy_to = |to: &Path| {
+            copy_on_write(
+                source,
+                to,
+                options.reflink_mode,
+                options.sparse_mode,
+                context,
+                #[cfg(unix)]
+                is_stream(source_metadata),
+                #[cfg(unix)]
+                nofollow,
+            )
+        };
+
+        let copy_debug = if options.atomic && !options.attributes_only {
+            // Replace what dest points to rather than the symlink itself,
+            // matching where a regular copy would write.
+            let target = if dest.is_symlink() {
+                fs::canonicalize(dest)?
+            } else {
+                dest.to_path_buf()
+            };
+            let temp = create_atomic_temp(&target)?;
+            let (copy_debug, temp_file) = copy_to(&temp)?;
+            replace_atomically(
+                temp,
+                temp_file,
+                &target,
+                source,
+                source_metadata,
+                options,
+                context,
+            )?;
+            copy_debug
+        } else {
+            let (copy_debug, dest_file) = copy_to(dest)?;
+            if options.fsync {
+                sync_destination(dest, dest_file)?;
+            }
+            copy_debug
+        };
 
         if !options.attributes_only && options.debug {
             show_debug(&copy_debug)
@@ -2892,6 +2959,155 @@ fn copy_helper(
     Ok(())
 }
 
+/// Create a uniquely named empty file next to `dest` for `--atomic` to copy
+/// into. It is removed again when the returned path is dropped.
+fn create_atomic_temp(dest: &Path) -> CopyResult<TempPath> {
+    let dir = match dest.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    };
+    let mut prefix = OsString::from(".");
+    prefix.push(dest.file_name().unwrap_or_default());
+    prefix.push(".");
+    tempfile::Builder::new()
+        .prefix(&prefix)
+        .tempfile_in(dir)
+        .map(NamedTempFile::into_temp_path)
+        .map_err(|e| {
+            CpError::IoErrContext(e, translate!("cp-error-atomic-temp", "dir" => dir.quote()))
+        })
+}
+
+/// Give the fully written `temp` the metadata of the copy, then rename it over
+/// `dest` so that readers see either the old file or the new one. With
+/// `--fsync`, `temp` is synced before the rename, and the directory after it.
+///
+/// A rename can't cross devices, e.g. when `dest` is a bind mount. In that case
+/// the contents are copied over `dest` in place instead, with a warning.
+fn replace_atomically(
+    temp: TempPath,
+    temp_file: Option<fs::File>,
+    dest: &Path,
+    source: &Path,
+    source_metadata: &Metadata,
+    options: &Options,
+    context: &str,
+) -> CopyResult<()> {
+    let dest_metadata = fs::metadata(dest).ok();
+    let permissions = calculate_dest_permissions(
+        dest_metadata.as_ref(),
+        dest,
+        source_metadata,
+        options,
+        context,
+    );
+    fs::set_permissions(&temp, permissions)
+        .map_err(|e| CpError::IoErrContext(e, context.to_owned()))?;
+    copy_attributes(
+        source,
+        &temp,
+        &options.attributes,
+        false,
+        options.set_selinux_context,
+    )?;
+    if options.fsync {
+        sync_destination_file(&temp, temp_file)?;
+    }
+
+    match temp.persist(dest) {
+        Ok(()) => {}
+        Err(e) if e.error.kind() == io::ErrorKind::CrossesDevices => {
+            show_warning!(
+                "{}",
+                translate!("cp-warning-atomic-fallback", "dest" => dest.quote())
+            );
+            let (_, dest_file) = copy_on_write(
+                &e.path,
+                dest,
+                options.reflink_mode,
+                options.sparse_mode,
+                context,
+                #[cfg(unix)]
+                false,
+                #[cfg(unix)]
+                false,
+            )?;
+            if options.fsync {
+                sync_destination_file(dest, dest_file)?;
+            }
+        }
+        Err(e) => {
+            return Err(CpError::IoErrContext(
+                e.error,
+                translate!("cp-error-atomic-rename", "temp" => e.path.quote(), "dest" => dest.quote()),
+            ));
+        }
+    }
+    if options.fsync {
+        sync_parent_directory(dest)?;
+    }
+    Ok(())
+}
+
+/// Flush a copied regular file, and the directory entry naming it, to disk.
+fn sync_destination(dest: &Path, file: Option<fs::File>) -> CopyResult<()> {
+    sync_destination_file(dest, file)?;
+    sync_parent_directory(dest)
+}
+
+/// Flush the contents of a copied regular file to disk, through `file` if the
+/// copy returned it.
+///
+/// Other destinations, such as devices reached through a symlink, are left
+/// alone since they may not support syncing.
+fn sync_destination_file(dest: &Path, file: Option<fs::File>) -> CopyResult<()> {
+    let context = || translate!("cp-error-sync", "path" => dest.quote());
+    let file = if let Some(file) = file {
+        file
+    } else {
+        // Check the type before opening, as opening a FIFO would block.
+        if !fs::metadata(dest)
+            .map_err(|e| CpError::IoErrContext(e, context()))?
+            .is_file()
+        {
+            return Ok(());
+        }
+        // Any descriptor can be synced, so fall back to write access for
+        // destinations that cannot be read.
+        fs::File::open(dest)
+            .or_else(|_| OpenOptions::new().write(true).open(dest))
+            .map_err(|e| CpError::IoErrContext(e, context()))?
+    };
+    if !file
+        .metadata()
+        .map_err(|e| CpError::IoErrContext(e, context()))?
+        .is_file()
+    {
+        return Ok(());
+    }
+    file.sync_all()
+        .map_err(|e| CpError::IoErrContext(e, context()))
+}
+
+/// Flush the directory entry naming `dest` to disk.
+fn sync_parent_directory(dest: &Path) -> CopyResult<()> {
+    #[cfg(unix)]
+    {
+        let parent = match dest.parent() {
+            Some(p) if !p.as_os_str().is_empty() => p,
+            _ => Path::new("."),
+        };
+        fs::File::open(parent)
+            .and_then(|dir| dir.sync_all())
+            .map_err(|e| {
+                CpError::IoErrContext(e, translate!("cp-error-sync", "path" => parent.quote()))
+            })?;
+    }
+    #[cfg(not(unix))]
+    let _ = dest;
+    Ok(())
+}
+
 // "Copies" a FIFO by creating a new one. This workaround is because Rust's
 // built-in fs::copy does not handle FIFOs (see rust-lang/rust/issues/79390).
 #[cfg(unix)]
@@ -2,9 +2,11 @@
 //
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
-// spell-checker:ignore ficlone reflink ftruncate pwrite fiemap lseek nofollow
+// spell-checker:ignore ficlone reflink ftruncate pwrite fiemap lseek nofollow procfs
 
-use rustix::fs::{SeekFrom, ftruncate, ioctl_ficlone, seek};
+use rustix::fs::{SeekFrom, copy_file_range, ftruncate, ioctl_ficlone, seek};
+use rustix::io::Errno;
+use std::fs::File;
 use std::io::Read;
 use std::os::unix::fs::FileExt;
 use std::os::unix::fs::FileTypeExt;
@@ -21,11 +23,24 @@ use crate::{
     is_stream,
 };
 
+/// The largest amount of data requested from a single `copy_file_range` call.
+const COPY_FILE_RANGE_CHUNK: usize = 1 << 30;
+
 // Replacement for `std::fs::copy` that uses the safe-copy primitives but
 // only applies `O_NOFOLLOW` to the *source* open. The destination is
 // followed if it is a pre-existing symlink, matching GNU cp -d/-P which
 // only forbid dereferencing on the source side.
-fn fs_copy<P, Q>(source: P, dest: Q, source_nofollow: bool, context: &str) -> CopyResult<()>
+//
+// With `offload`, the data is first copied in the kernel with
+// `copy_file_range`, which may share extents like a reflink does, and the
+// outcome is recorded there for `--debug`.
+fn fs_copy<P, Q>(
+    source: P,
+    dest: Q,
+    source_nofollow: bool,
+    offload: Option<&mut OffloadReflinkDebug>,
+    context: &str,
+) -> CopyResult<File>
 where
     P: AsRef<Path>,
     Q: AsRef<Path>,
@@ -38,9 +53,54 @@ where
             translate!("cp-error-cannot-create-regular-file", "path" => dest.as_ref().quote()),
         )
     })?;
+    if let Some(offload) = offload {
+        let outcome =
+            offload_copy(&src, &dst).map_err(|e| CpError::IoErrContext(e, context.to_owned()))?;
+        if let Some(outcome) = outcome {
+            *offload = outcome;
+        }
+    }
     buf_copy::copy_fast(&mut src, &mut dst)
         .map_err(|e| CpError::IoErrContext(e, context.to_owned()))?;
-    Ok(())
+    Ok(dst)
+}
+
+/// Copy as much as possible from `src` to `dst` with `copy_file_range`.
+///
+/// Both file offsets advance with the copied data, so the caller finishes the
+/// copy from where this stopped without copying anything twice. This stops
+/// early when the kernel cannot offload the copy (e.g. across filesystems),
+/// and also on a zero-length result, since some filesystems such as procfs
+/// report that before the end of the data.
+///
+/// Returns [`OffloadReflinkDebug::Yes`] if any data was offloaded,
+/// [`OffloadReflinkDebug::Unsupported`] if the kernel refused it outright,
+/// and `None` if nothing was learned, e.g. for an empty file.
+fn offload_copy(src: &File, dst: &File) -> std::io::Result<Option<OffloadReflinkDebug>> {
+    let mut offloaded = false;
+    loop {
+        match copy_file_range(src, None, dst, None, COPY_FILE_RANGE_CHUNK) {
+            Ok(0) => return Ok(offloaded.then_some(OffloadReflinkDebug::Yes)),
+            Ok(_) => offloaded = true,
+            Err(Errno::INTR) => {}
+            Err(
+                Errno::XDEV
+                | Errno::INVAL
+                | Errno::NOSYS
+                | Errno::OPNOTSUPP
+                | Errno::BADF
+                | Errno::PERM
+                | Errno::TXTBSY,
+            ) => {
+                return Ok(Some(if offloaded {
+                    OffloadReflinkDebug::Yes
+                } else {
+                    OffloadReflinkDebug::Unsupported
+                }));
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
 }
 
 /// The fallback behavior for [`clone`] on failed system call.
@@ -49,7 +109,7 @@ enum CloneFallback {
     /// Raise an error.
     Error,
 
-    /// Use [`std::fs::copy`].
+    /// Use [`fs_copy`].
     FSCopy,
 
     /// Use [`sparse_copy`]
@@ -64,7 +124,7 @@ enum CloneFallback {
 enum CopyMethod {
     /// Do a sparse copy
     SparseCopy,
-    /// Use [`std::fs::copy`].
+    /// Use [`fs_copy`].
     FSCopy,
     /// Default (can either be [`CopyMethod::SparseCopy`] or [`CopyMethod::FSCopy`])
     Default,
@@ -74,14 +134,17 @@ enum CopyMethod {
 
 /// Use the Linux `ioctl_ficlone` API to do a copy-on-write clone.
 ///
-/// `fallback` controls what to do if the system call fails.
+/// `fallback` controls what to do if the system call fails. With `offload`,
+/// the [`CloneFallback::FSCopy`] fallback first tries to offload the copy to
+/// the kernel, and records the outcome there for `--debug`.
 fn clone<P>(
     source: P,
     dest: P,
     fallback: CloneFallback,
     nofollow: bool,
     context: &str,
-) -> CopyResult<()>
+    offload: Option<&mut OffloadReflinkDebug>,
+) -> CopyResult<File>
 where
     P: AsRef<Path>,
 {
@@ -93,20 +156,22 @@ where
             translate!("cp-error-cannot-create-regular-file", "path" => dest.as_ref().quote()),
         )
     })?;
-    if ioctl_ficlone(dst_file, src_file).is_err() {
+    // Keep the destination open while a fallback reopens it, so that a
+    // reader of a FIFO does not see the end of the data in between.
+    if ioctl_ficlone(&dst_file, &src_file).is_err() {
         return match fallback {
             CloneFallback::Error => Err(CpError::IoErrContext(
                 std::io::Error::last_os_error(),
                 context.to_owned(),
             )),
-            CloneFallback::FSCopy => fs_copy(source, dest, nofollow, context),
+            CloneFallback::FSCopy => fs_copy(source, dest, nofollow, offload, context),
             CloneFallback::SparseCopy => sparse_copy(source, dest, nofollow, context),
             CloneFallback::SparseCopyWithoutHole => {
                 sparse_copy_without_hole(source, dest, nofollow, context)
             }
         };
     }
-    Ok(())
+    Ok(dst_file)
 }
 
 /// Checks whether a file contains any non null bytes i.e. any byte != 0x0
@@ -142,7 +207,12 @@ fn check_sparse_detection(source: &Path, nofollow: bool) -> Result<bool, std::io
 
 /// Optimized [`sparse_copy`] doesn't create holes for large sequences of zeros in non `sparse_files`
 /// Used when `--sparse=auto`
-fn sparse_copy_without_hole<P>(source: P, dest: P, nofollow: bool, context: &str) -> CopyResult<()>
+fn sparse_copy_without_hole<P>(
+    source: P,
+    dest: P,
+    nofollow: bool,
+    context: &str,
+) -> CopyResult<File>
 where
     P: AsRef<Path>,
 {
@@ -184,11 +254,11 @@ where
         }
         current_offset = hole;
     }
-    Ok(())
+    Ok(dst_file)
 }
 /// Perform a sparse copy from one file to another.
 /// Creates a holes for large sequences of zeros in `non_sparse_files`, used for `--sparse=always`
-fn sparse_copy<P>(source: P, dest: P, nofollow: bool, context: &str) -> CopyResult<()>
+fn sparse_copy<P>(source: P, dest: P, nofollow: bool, context: &str) -> CopyResult<File>
 where
     P: AsRef<Path>,
 {
@@ -229,7 +299,7 @@ where
         }
         current_offset += this_read;
     }
-    Ok(())
+    Ok(dst_file)
 }
 
 /// Checks whether an existing destination is a fifo
@@ -239,7 +309,7 @@ fn check_dest_is_fifo(dest: &Path) -> bool {
 }
 
 /// Copy the contents of a stream from `source` to `dest`.
-fn copy_stream<P>(source: P, dest: P, nofollow: bool, context: &str) -> CopyResult<()>
+fn copy_stream<P>(source: P, dest: P, nofollow: bool, context: &str) -> CopyResult<File>
 where
     P: AsRef<Path>,
 {
@@ -286,10 +356,12 @@ where
         .map_err(|e| std::io::Error::other(format!("{e}")))
         .map_err(&ctx_err)?;
 
-    Ok(())
+    Ok(dst_file)
 }
 
 /// Copies `source` to `dest` using copy-on-write if possible.
+///
+/// Returns the destination file as written, so that `--fsync` can sync it.
 pub(crate) fn copy_on_write(
     source: &Path,
     dest: &Path,
@@ -298,7 +370,7 @@ pub(crate) fn copy_on_write(
     context: &str,
     source_is_stream: bool,
     nofollow: bool,
-) -> CopyResult<CopyDebug> {
+) -> CopyResult<(CopyDebug, Option<File>)> {
     let mut copy_debug = CopyDebug {
         offload: OffloadReflinkDebug::Unknown,
         reflink: OffloadReflinkDebug::Unsupported,
@@ -321,7 +393,7 @@ pub(crate) fn copy_on_write(
                 }
 
                 match copy_method {
-                    CopyMethod::FSCopy => fs_copy(source, dest, nofollow, context),
+                    CopyMethod::FSCopy => fs_copy(source, dest, nofollow, None, context),
                     _ => sparse_copy(source, dest, nofollow, context),
                 }
             }
@@ -337,7 +409,7 @@ pub(crate) fn copy_on_write(
                 if let Ok(debug) = result {
                     copy_debug = debug;
                 }
-                fs_copy(source, dest, nofollow, context)
+                fs_copy(source, dest, nofollow, None, context)
             }
         }
         (ReflinkMode::Never, SparseMode::Auto) => {
@@ -358,7 +430,7 @@ pub(crate) fn copy_on_write(
                     CopyMethod::SparseCopyWithoutHole => {
                         sparse_copy_without_hole(source, dest, nofollow, context)
                     }
-                    _ => fs_copy(source, dest, nofollow, context),
+                    _ => fs_copy(source, dest, nofollow, None, context),
                 }
             }
         }
@@ -378,9 +450,16 @@ pub(crate) fn copy_on_write(
 
                 match copy_method {
                     CopyMethod::FSCopy => {
-                        clone(source, dest, CloneFallback::FSCopy, nofollow, context)
+                        clone(source, dest, CloneFallback::FSCopy, nofollow, context, None)
                     }
-                    _ => clone(source, dest, CloneFallback::SparseCopy, nofollow, context),
+                    _ => clone(
+                        source,
+                        dest,
+                        CloneFallback::SparseCopy,
+                        nofollow,
+                        context,
+                        None,
+                    ),
                 }
             }
         }
@@ -396,7 +475,7 @@ pub(crate) fn copy_on_write(
                     copy_debug = debug;
                 }
 
-                clone(source, dest, CloneFallback::FSCopy, nofollow, context)
+                clone(source, dest, CloneFallback::FSCopy, nofollow, context, None)
             }
         }
         (ReflinkMode::Auto, SparseMode::Auto) => {
@@ -418,8 +497,16 @@ pub(crate) fn copy_on_write(
                         CloneFallback::SparseCopyWithoutHole,
                         nofollow,
                         context,
+                        None,
+                    ),
+                    _ => clone(
+                        source,
+                        dest,
+                        CloneFallback::FSCopy,
+                        nofollow,
+                        context,
+                        Some(&mut copy_debug.offload),
                     ),
-                    _ => clone(source, dest, CloneFallback::FSCopy, nofollow, context),
                 }
             }
         }
@@ -428,14 +515,14 @@ pub(crate) fn copy_on_write(
             copy_debug.sparse_detection = SparseDebug::No;
             copy_debug.reflink = OffloadReflinkDebug::Yes;
 
-            clone(source, dest, CloneFallback::Error, nofollow, context)
+            clone(source, dest, CloneFallback::Error, nofollow, context, None)
         }
         (ReflinkMode::Always, _) => {
             return Err(translate!("cp-error-reflink-always-sparse-auto").into());
         }
     };
-    result?;
-    Ok(copy_debug)
+    let dst_file = result?;
+    Ok((copy_debug, Some(dst_file)))
 }
 
 /// Handles debug results when flags are "--reflink=auto" and "--sparse=always" and specifies what
@@ -22,6 +22,9 @@ use crate::{
 };
 
 /// Copies `source` to `dest` using copy-on-write if possible.
+///
+/// Returns the destination file unless it was cloned by path, so that
+/// `--fsync` can sync it.
 pub(crate) fn copy_on_write(
     source: &Path,
     dest: &Path,
@@ -30,7 +33,7 @@ pub(crate) fn copy_on_write(
     context: &str,
     source_is_stream: bool,
     nofollow: bool,
-) -> CopyResult<CopyDebug> {
+) -> CopyResult<(CopyDebug, Option<File>)> {
     if sparse_mode != SparseMode::Auto {
         return Err(translate!("cp-error-sparse-not-supported")
             .to_string()
@@ -54,6 +57,7 @@ pub(crate) fn copy_on_write(
     let raw_pfn = unsafe { libc::dlsym(libc::RTLD_NEXT, clonefile.as_ptr()) };
 
     let mut error = 0;
+    let mut written = None;
     if !raw_pfn.is_null() {
         // Call clonefile(2).
         // Safety: Casting a C function pointer to a rust function value is one of the few
@@ -103,7 +107,7 @@ pub(crate) fn copy_on_write(
                 .into());
         }
         copy_debug.reflink = OffloadReflinkDebug::Yes;
-        if source_is_stream {
+        written = Some(if source_is_stream {
             let mut src_file =
                 File::open(source).map_err(|e| CpError::IoErrContext(e, context.to_owned()))?;
             let mode = 0o622 & !get_umask();
@@ -134,6 +138,7 @@ pub(crate) fn copy_on_write(
             buf_copy::copy_fast(&mut src_file, &mut dst_file)
                 .map_err(|_| std::io::Error::from(std::io::ErrorKind::Other))
                 .map_err(|e| CpError::IoErrContext(e, context.to_owned()))?;
+            dst_file
         } else {
             let mut src_file = open_source(source, nofollow)
                 .map_err(|e| CpError::IoErrContext(e, context.to_owned()))?;
@@ -145,8 +150,9 @@ pub(crate) fn copy_on_write(
             })?;
             std::io::copy(&mut src_file, &mut dst_file)
                 .map_err(|e| CpError::IoErrContext(e, context.to_owned()))?;
-        }
+            dst_file
+        });
     }
 
-    Ok(copy_debug)
+    Ok((copy_debug, written))
 }
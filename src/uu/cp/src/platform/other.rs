@@ -12,13 +12,15 @@ use crate::{
 };
 
 /// Copies `source` to `dest` for systems without copy-on-write
+///
+/// The copy is made by path, so no destination file is returned.
 pub(crate) fn copy_on_write(
     source: &Path,
     dest: &Path,
     reflink_mode: ReflinkMode,
     sparse_mode: SparseMode,
     context: &str,
-) -> CopyResult<CopyDebug> {
+) -> CopyResult<(CopyDebug, Option<fs::File>)> {
     if reflink_mode != ReflinkMode::Never {
         return Err(translate!("cp-error-reflink-not-supported")
             .to_string()
@@ -36,5 +38,5 @@ pub(crate) fn copy_on_write(
     };
     fs::copy(source, dest).map_err(|e| CpError::IoErrContext(e, context.to_owned()))?;
 
-    Ok(copy_debug)
+    Ok((copy_debug, None))
 }
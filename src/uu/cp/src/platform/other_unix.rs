@@ -3,6 +3,7 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 // spell-checker:ignore reflink
+use std::fs::File;
 use std::path::Path;
 
 use uucore::buf_copy;
@@ -16,6 +17,8 @@ use crate::{
 };
 
 /// Copies `source` to `dest` for systems without copy-on-write
+///
+/// Returns the destination file as written, so that `--fsync` can sync it.
 pub(crate) fn copy_on_write(
     source: &Path,
     dest: &Path,
@@ -24,7 +27,7 @@ pub(crate) fn copy_on_write(
     context: &str,
     source_is_stream: bool,
     nofollow: bool,
-) -> CopyResult<CopyDebug> {
+) -> CopyResult<(CopyDebug, Option<File>)> {
     if reflink_mode != ReflinkMode::Never {
         return Err(translate!("cp-error-reflink-not-supported")
             .to_string()
@@ -61,7 +64,7 @@ pub(crate) fn copy_on_write(
             .map_err(|_| std::io::Error::from(std::io::ErrorKind::Other))
             .map_err(|e| CpError::IoErrContext(e, context.to_owned()))?;
 
-        return Ok(copy_debug);
+        return Ok((copy_debug, Some(dst_file)));
     }
 
     // Replacement for fs::copy: restrictive 0o600 dest mode (#10011) and
@@ -79,5 +82,5 @@ pub(crate) fn copy_on_write(
     std::io::copy(&mut src_file, &mut dst_file)
         .map_err(|e| CpError::IoErrContext(e, context.to_owned()))?;
 
-    Ok(copy_debug)
+    Ok((copy_debug, Some(dst_file)))
 }
@@ -229,13 +229,15 @@ fn is_sparse(file: &File) -> std::io::Result<bool> {
 /// rejected, while `--reflink=auto` ("clone if possible") falls back to the
 /// plain behavior like on other platforms without reflink support. Sparse
 /// copies are implemented via the `FSCTL_SET_SPARSE` device control.
+///
+/// The copies are made by path, so no destination file is returned.
 pub(crate) fn copy_on_write(
     source: &Path,
     dest: &Path,
     reflink_mode: ReflinkMode,
     sparse_mode: SparseMode,
     context: &str,
-) -> CopyResult<CopyDebug> {
+) -> CopyResult<(CopyDebug, Option<File>)> {
     if reflink_mode == ReflinkMode::Always {
         return Err(translate!("cp-error-reflink-not-supported")
             .to_string()
@@ -281,7 +283,7 @@ pub(crate) fn copy_on_write(
         }
     }
 
-    Ok(copy_debug)
+    Ok((copy_debug, None))
 }
 
 #[cfg(test)]
@@ -128,6 +128,7 @@ dd-error-cannot-skip-invalid = '{ $file }': cannot skip: Invalid argument
 dd-error-cannot-seek-invalid = '{ $output }': cannot seek: Invalid argument
 dd-error-not-directory = setting flags for '{ $file }': Not a directory
 dd-error-failed-discard-cache = failed to discard cache for: { $file }
+dd-error-reading = error reading { $file }
 
 # Parse errors
 dd-error-unrecognized-operand = Unrecognized operand '{ $operand }'
@@ -128,6 +128,7 @@ dd-error-cannot-skip-invalid = '{ $file }' : impossible d'ignorer : Argument inv
 dd-error-cannot-seek-invalid = '{ $output }' : impossible de rechercher : Argument invalide
 dd-error-not-directory = définir les indicateurs pour '{ $file }' : N'est pas un répertoire
 dd-error-failed-discard-cache = échec de la suppression du cache pour : { $file }
+dd-error-reading = erreur de lecture de { $file }
 
 # Parse errors
 dd-error-unrecognized-operand = Opérande non reconnue '{ $operand }'
@@ -49,9 +49,9 @@ use std::time::{Duration, Instant};
 use clap::{Arg, Command};
 use gcd::Gcd;
 use uucore::display::Quotable;
-use uucore::error::{FromIo, UResult};
 #[cfg(unix)]
-use uucore::error::{USimpleError, set_exit_code};
+use uucore::error::USimpleError;
+use uucore::error::{FromIo, UResult, set_exit_code, strip_errno};
 #[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
 use uucore::show_if_err;
 use uucore::{format_usage, show_error};
@@ -334,6 +334,14 @@ impl Read for Source {
     }
 }
 
+/// The blocks copied before a fill of the buffer, for `conv=noerror` reports.
+#[derive(Clone, Copy)]
+struct CopyProgress {
+    rstat: ReadStat,
+    wstat: WriteStat,
+    start: Instant,
+}
+
 /// The source of the data, configured with the given settings.
 ///
 /// Use the [`Input::new_stdin`] or [`Input::new_file`] functions to
@@ -477,7 +485,6 @@ impl Read for Input<'_> {
                 }
                 Ok(len) => return Ok(len),
                 Err(e) if e.kind() == io::ErrorKind::Interrupted => (),
-                Err(_) if self.settings.iconv.noerror => return Ok(base_idx),
                 Err(e) => return Err(e),
             }
         }
@@ -513,18 +520,91 @@ impl Input<'_> {
         // TODO: Is there a way to discard filesystem cache on other targets?
     }
 
+    /// Report a read error under `conv=noerror` and seek past the bad
+    /// block of `len` bytes, so that the next read gets the data after it.
+    ///
+    /// As in GNU dd, the error is followed by the statistics so far, which
+    /// are the blocks copied before this fill plus those in `filled`.
+    /// Both are left out with `status=none`.
+    ///
+    /// If the input cannot be repositioned, the exit status is set to 1.
+    fn skip_read_error(
+        &mut self,
+        e: &io::Error,
+        len: usize,
+        progress: &CopyProgress,
+        filled: ReadStat,
+    ) {
+        let status = self.settings.status;
+        if status != Some(StatusLevel::None) {
+            let file = self.settings.infile.as_ref().map_or_else(
+                || translate!("dd-standard-input"),
+                |f| f.quote().to_string(),
+            );
+            show_error!(
+                "{}: {}",
+                translate!("dd-error-reading", "file" => file),
+                strip_errno(e)
+            );
+            let mut rstat = progress.rstat;
+            rstat += filled;
+            let update = ProgUpdate::new(
+                rstat,
+                progress.wstat,
+                progress.start.elapsed(),
+                ProgUpdateType::Final,
+            );
+            update.print_final_stats(status, false);
+        }
+        let offset = i64::try_from(len).unwrap_or(i64::MAX);
+        let seeked = match &mut self.src {
+            Source::File(f) => f.seek(SeekFrom::Current(offset)).is_ok(),
+            #[cfg(unix)]
+            Source::StdinFile(f) => f.seek(SeekFrom::Current(offset)).is_ok(),
+            // Pipes can't be repositioned, and the data after the error
+            // is read anyway.
+            _ => true,
+        };
+        if !seeked {
+            set_exit_code(1);
+        }
+    }
+
     /// Fills a given buffer.
     /// Reads in increments of 'self.ibs'.
     /// The start of each ibs-sized read follows the previous one; a short
     /// read ends the fill, so the bytes received so far form one partial
     /// record for the copy loop (as in GNU dd).
-    fn fill_consecutive(&mut self, buf: &mut Vec<u8>) -> io::Result<ReadStat> {
+    fn fill_consecutive(
+        &mut self,
+        buf: &mut Vec<u8>,
+        progress: &CopyProgress,
+    ) -> io::Result<ReadStat> {
         let mut reads_complete = 0;
         let mut reads_partial = 0;
         let mut bytes_total = 0;
 
         for chunk in buf.chunks_mut(self.settings.ibs) {
-            match self.read(chunk)? {
+            let rlen = loop {
+                match self.read(chunk) {
+                    Ok(rlen) => break rlen,
+                    // Without `conv=sync` there is nothing to put in place
+                    // of the bad block, so the fill ends with what it has.
+                    // An empty fill would be taken for the end of the input,
+                    // so then read on after the bad block instead.
+                    Err(e) if self.settings.iconv.noerror => {
+                        let len = chunk.len();
+                        let filled =
+                            ReadStat::new(reads_complete, reads_partial, 0, bytes_total as u64);
+                        self.skip_read_error(&e, len, progress, filled);
+                        if bytes_total > 0 {
+                            break 0;
+                        }
+                    }
+                    Err(e) => return Err(e),
+                }
+            };
+            match rlen {
                 rlen if rlen == self.settings.ibs => {
                     bytes_total += rlen;
                     reads_complete += 1;
@@ -555,7 +635,13 @@ impl Input<'_> {
     /// Fills a given buffer.
     /// Reads in increments of 'self.ibs'.
     /// The start of each ibs-sized read is aligned to multiples of ibs; remaining space is filled with the 'pad' byte.
-    fn fill_blocks(&mut self, buf: &mut Vec<u8>, pad: u8) -> io::Result<ReadStat> {
+    /// Under `conv=noerror`, a block that fails to read is entirely padded and counted as a partial read.
+    fn fill_blocks(
+        &mut self,
+        buf: &mut Vec<u8>,
+        pad: u8,
+        progress: &CopyProgress,
+    ) -> io::Result<ReadStat> {
         let mut reads_complete = 0;
         let mut reads_partial = 0;
         let mut base_idx = 0;
@@ -565,13 +651,25 @@ impl Input<'_> {
             let next_blk = cmp::min(base_idx + self.settings.ibs, buf.len());
             let target_len = next_blk - base_idx;
 
-            match self.read(&mut buf[base_idx..next_blk])? {
+            let rlen = match self.read(&mut buf[base_idx..next_blk]) {
+                Ok(rlen) => rlen,
+                Err(e) if self.settings.iconv.noerror => {
+                    let filled =
+                        ReadStat::new(reads_complete, reads_partial, 0, bytes_total as u64);
+                    self.skip_read_error(&e, target_len, progress, filled);
+                    reads_partial += 1;
+                    buf[base_idx..next_blk].fill(pad);
+                    base_idx += self.settings.ibs;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            match rlen {
                 0 => break,
                 rlen if rlen < target_len => {
                     bytes_total += rlen;
                     reads_partial += 1;
-                    let padding = vec![pad; target_len - rlen];
-                    buf.splice(base_idx + rlen..next_blk, padding);
+                    buf[base_idx + rlen..next_blk].fill(pad);
                 }
                 rlen => {
                     bytes_total += rlen;
@@ -1233,7 +1331,12 @@ fn dd_copy(mut i: Input, o: Output) -> io::Result<()> {
         // best buffer size for reading based on the number of
         // blocks already read and the number of blocks remaining.
         let loop_bsize = calc_loop_bsize(i.settings.count, &rstat, &wstat, i.settings.ibs, bsize);
-        let rstat_update = read_helper(&mut i, &mut buf, loop_bsize)?;
+        let progress = CopyProgress {
+            rstat,
+            wstat,
+            start,
+        };
+        let rstat_update = read_helper(&mut i, &mut buf, loop_bsize, &progress)?;
         if rstat_update.is_empty() {
             if input_nocache {
                 i.discard_cache(read_offset, 0);
@@ -1375,7 +1478,12 @@ fn make_linux_oflags(oflags: &OFlags) -> Option<libc::c_int> {
 /// `conv=swab` or `conv=block` command-line arguments. This function
 /// mutates the `buf` argument in-place. The returned [`ReadStat`]
 /// indicates how many blocks were read.
-fn read_helper(i: &mut Input, buf: &mut Vec<u8>, bsize: usize) -> io::Result<ReadStat> {
+fn read_helper(
+    i: &mut Input,
+    buf: &mut Vec<u8>,
+    bsize: usize,
+    progress: &CopyProgress,
+) -> io::Result<ReadStat> {
     // Local Helper Fns -------------------------------------------------
     fn perform_swab(buf: &mut [u8]) {
         for base in (1..buf.len()).step_by(2) {
@@ -1389,8 +1497,8 @@ fn read_helper(i: &mut Input, buf: &mut Vec<u8>, bsize: usize) -> io::Result<Rea
     buf.resize(bsize, BUF_INIT_BYTE);
 
     let mut rstat = match i.settings.iconv.sync {
-        Some(ch) => i.fill_blocks(buf, ch)?,
-        _ => i.fill_consecutive(buf)?,
+        Some(ch) => i.fill_blocks(buf, ch, progress)?,
+        _ => i.fill_consecutive(buf, progress)?,
     };
     // Return early if no data
     if rstat.reads_complete == 0 && rstat.reads_partial == 0 {
@@ -295,8 +295,7 @@ pub(crate) struct ReadStat {
 
 impl ReadStat {
     /// Create a new instance.
-    #[allow(dead_code)]
-    fn new(complete: u64, partial: u64, truncated: u32, bytes_total: u64) -> Self {
+    pub(crate) fn new(complete: u64, partial: u64, truncated: u32, bytes_total: u64) -> Self {
         Self {
             reads_complete: complete,
             reads_partial: partial,
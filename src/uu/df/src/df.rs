@@ -221,18 +221,27 @@ impl Options {
 
 /// Whether to display the mount info given the inclusion settings.
 fn is_included(mi: &MountInfo, opt: &Options) -> bool {
-    // Don't show remote filesystems if `--local` has been given.
-    !(mi.remote && opt.show_local_fs) &&
-
     // Don't show pseudo filesystems unless `--all` has been given.
     // The "lofs" filesystem is a loopback
     // filesystem present on Solaris and FreeBSD systems. It
     // is similar to a symbolic link.
-    !((mi.dummy || mi.fs_type == "lofs") && !opt.show_all_fs) &&
+    (opt.show_all_fs || !(mi.dummy || mi.fs_type == "lofs")) && is_listed_included(mi, opt)
+}
+
+/// Whether to display the mount info of a file given on the command line.
+///
+/// Unlike [`is_included`], pseudo filesystems are kept, as in GNU `df`.
+fn is_listed_included(mi: &MountInfo, opt: &Options) -> bool {
+    // Don't show remote filesystems if `--local` has been given.
+    if mi.remote && opt.show_local_fs {
+        return false;
+    }
 
     // Don't show filesystems if they have been explicitly excluded.
-    !opt.exclude.as_ref().is_some_and(|e| e.contains(&mi.fs_type)) &&
-    opt.include.as_ref().is_none_or(|i| i.contains(&mi.fs_type))
+    !opt.exclude
+        .as_ref()
+        .is_some_and(|e| e.contains(&mi.fs_type))
+        && opt.include.as_ref().is_none_or(|i| i.contains(&mi.fs_type))
 }
 
 /// Whether the mount info in `m2` should be prioritized over `m1`.
@@ -360,7 +369,7 @@ where
 
         match fs_result {
             Ok(fs) => {
-                if is_included(&fs.mount_info, opt) {
+                if is_listed_included(&fs.mount_info, opt) {
                     result.push(fs);
                 }
             }
@@ -719,7 +728,7 @@ mod tests {
 
     mod is_included {
 
-        use crate::{Options, is_included};
+        use crate::{Options, is_included, is_listed_included};
         use uucore::fsext::MountInfo;
 
         /// Instantiate a [`MountInfo`] with the given fields.
@@ -770,6 +779,13 @@ mod tests {
             assert!(!is_included(&m, &opt));
         }
 
+        #[test]
+        fn test_dummy_listed_included() {
+            let opt = Options::default();
+            let m = mount_info("proc", "/proc", false, true);
+            assert!(is_listed_included(&m, &opt));
+        }
+
         #[test]
         fn test_exclude_match() {
             let exclude = Some(vec![String::from("ext4")]);
@@ -485,20 +485,17 @@ impl Table {
         // total counts in the last row of the table.
         let mut total = Row::new(&translate!("df-total"));
 
+        // Empty filesystems have already been filtered out by the caller,
+        // unless they were named on the command line.
         for filesystem in filesystems {
-            // If the filesystem is not empty, or if the options require
-            // showing all filesystems, then print the data as a row in
-            // the output table.
-            if options.show_all_fs || filesystem.usage.blocks > 0 {
-                let row = Row::from_filesystem(filesystem, &options.block_size);
-                let fmt = RowFormatter::new(&row, options, false);
-                let values = fmt.get_cells();
-                if options.show_total {
-                    total += row;
-                }
-
-                rows.push(values);
+            let row = Row::from_filesystem(filesystem, &options.block_size);
+            let fmt = RowFormatter::new(&row, options, false);
+            let values = fmt.get_cells();
+            if options.show_total {
+                total += row;
             }
+
+            rows.push(values);
         }
 
         if options.show_total {
@@ -933,6 +930,37 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_row_formatter_si_and_binary_differ() {
+        init();
+        let row = Row {
+            fs_device: "my_device".to_string(),
+            fs_mount: "my_mount".into(),
+
+            bytes: BytesCell::new(2_000_000, &BlockSize::default()),
+            bytes_used: BytesCell::new(1_500_000, &BlockSize::default()),
+            bytes_avail: BytesCell::new(1_000_000, &BlockSize::default()),
+            bytes_usage: Some(0.6),
+
+            ..Default::default()
+        };
+        let expected = [
+            (HumanReadable::Decimal, ["2.0M", "1.5M", "1.0M"]),
+            (HumanReadable::Binary, ["2.0M", "1.5M", "977K"]),
+        ];
+        for (human_readable, [size, used, avail]) in expected {
+            let options = Options {
+                human_readable: Some(human_readable),
+                ..Default::default()
+            };
+            let fmt = RowFormatter::new(&row, &options, false);
+            assert!(compare_cell_content(
+                fmt.get_cells(),
+                vec!("my_device", size, used, avail, "60%", "my_mount")
+            ));
+        }
+    }
+
     #[test]
     fn test_row_formatter_with_round_up_usage() {
         init();
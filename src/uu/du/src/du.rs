@@ -22,6 +22,7 @@ use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::mpsc;
 use std::thread;
+use std::time::SystemTime;
 use thiserror::Error;
 use uucore::display::{Quotable, print_verbatim};
 use uucore::error::{FromIo, UError, UResult, USimpleError, set_exit_code};
@@ -85,12 +86,12 @@ struct TraversalOptions {
     verbose: bool,
     excludes: Vec<Pattern>,
     // Avoid full-path stats unless `--time` needs them.
-    #[cfg(all(unix, not(target_os = "redox")))]
-    report_time: bool,
+    time: Option<MetadataTimeField>,
 }
 
 struct StatPrinter {
     total: bool,
+    separate_dirs: bool,
     inodes: bool,
     max_depth: Option<usize>,
     threshold: Option<Threshold>,
@@ -130,6 +131,8 @@ struct Stat {
     inodes: u64,
     inode: Option<FileInfo>,
     metadata: Metadata,
+    /// The `--time` timestamp; for a directory, the newest one in its subtree.
+    time: Option<SystemTime>,
 }
 
 impl Stat {
@@ -158,6 +161,9 @@ impl Stat {
 
         let file_info = get_file_info(path, &metadata);
         let blocks = get_blocks(path, &metadata);
+        let time = options
+            .time
+            .and_then(|field| metadata_get_time(&metadata, field));
 
         Ok(Self {
             path: path.to_path_buf(),
@@ -166,12 +172,17 @@ impl Stat {
             inodes: 1,
             inode: file_info,
             metadata,
+            time,
         })
     }
 
     /// Create a Stat using safe traversal methods with `DirFd` for the root directory
     #[cfg(all(unix, not(target_os = "redox")))]
-    fn new_from_dirfd(dir_fd: &DirFd, full_path: &Path) -> std::io::Result<Self> {
+    fn new_from_dirfd(
+        dir_fd: &DirFd,
+        full_path: &Path,
+        options: &TraversalOptions,
+    ) -> std::io::Result<Self> {
         // Get metadata for the directory itself using fstat
         let safe_metadata = dir_fd.metadata()?;
 
@@ -188,6 +199,9 @@ impl Stat {
         // This is still needed for compatibility but should work since we're dealing with
         // the root path which should be accessible
         let std_metadata = fs::symlink_metadata(full_path)?;
+        let time = options
+            .time
+            .and_then(|field| metadata_get_time(&std_metadata, field));
 
         Ok(Self {
             path: full_path.to_path_buf(),
@@ -200,6 +214,7 @@ impl Stat {
             inodes: 1,
             inode: file_info_option,
             metadata: std_metadata,
+            time,
         })
     }
 }
@@ -304,6 +319,36 @@ fn read_block_size(s: Option<&str>) -> UResult<u64> {
     }
 }
 
+/// Get the `--time` timestamp of an entry from its `fstatat` result.
+///
+/// That result carries no birth time, which is looked up by path instead.
+#[cfg(all(unix, not(target_os = "redox")))]
+fn stat_get_time(
+    md: &uucore::safe_traversal::Metadata,
+    path: &Path,
+    field: MetadataTimeField,
+) -> Option<SystemTime> {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let (secs, nsecs) = match field {
+        MetadataTimeField::Modification => (md.mtime(), md.mtime_nsec()),
+        MetadataTimeField::Access => (md.atime(), md.atime_nsec()),
+        MetadataTimeField::Change => (md.ctime(), md.ctime_nsec()),
+        MetadataTimeField::Birth => {
+            return fs::symlink_metadata(path)
+                .ok()
+                .and_then(|metadata| metadata_get_time(&metadata, field));
+        }
+    };
+    let mut time = UNIX_EPOCH;
+    if secs >= 0 {
+        time += Duration::from_secs(secs as u64);
+    } else {
+        time -= Duration::from_secs(secs.unsigned_abs());
+    }
+    Some(time + Duration::from_nanos(nsecs as u64))
+}
+
 #[cfg(all(unix, not(target_os = "redox")))]
 // Implement safe_du on Unix (except Redox which lacks full stat support)
 // This is done for TOCTOU safety
@@ -323,22 +368,12 @@ fn safe_du(
     // in which case we fall back to opening it directly with a DirFd.
     let initial_stat = initial_stat.unwrap_or_else(|| Stat::new(path, None, options));
     let mut my_stat = match initial_stat {
-        Ok(mut s) => {
-            // For subdirectories the `metadata` field is a cheap placeholder (the
-            // parent directory's metadata). It is only consulted by `--time`, so we
-            // only pay for a real stat of this entry when time reporting is asked for.
-            if options.report_time && parent_fd.is_some() {
-                if let Ok(md) = fs::symlink_metadata(&s.path) {
-                    s.metadata = md;
-                }
-            }
-            s
-        }
+        Ok(s) => s,
         Err(_e) => {
             // Only the root path can reach this branch (subdirectories always pass a
             // valid stat). Try using our DirFd method for the root directory.
             match DirFd::open(path, SymlinkBehavior::Follow) {
-                Ok(dir_fd) => match Stat::new_from_dirfd(&dir_fd, path) {
+                Ok(dir_fd) => match Stat::new_from_dirfd(&dir_fd, path, options) {
                     Ok(s) => s,
                     Err(e) => {
                         let error = e.map_err_context(
@@ -442,12 +477,20 @@ fn safe_du(
             dev_id: entry_stat.st_dev as u64,
         });
 
+        let entry_path = path.join(&entry_name);
+        // The `metadata` field below is a cheap placeholder (the parent
+        // directory's metadata), so take the timestamp from the entry's stat.
+        let time = options.time.and_then(|field| {
+            let md = uucore::safe_traversal::Metadata::from_stat(entry_stat);
+            stat_get_time(&md, &entry_path, field)
+        });
+
         // For safe traversal, we need to handle stats differently
         // We can't use std::fs::Metadata since that requires the full path
         let this_stat = if is_dir {
             // For directories, recurse using safe_du
             Stat {
-                path: path.join(&entry_name),
+                path: entry_path,
                 size: 0,
                 #[allow(clippy::unnecessary_cast)]
                 blocks: entry_stat.st_blocks as u64,
@@ -456,11 +499,12 @@ fn safe_du(
                 // We need a fake metadata - create one from symlink_metadata of parent
                 // This is a workaround since we can't get real metadata without the full path
                 metadata: my_stat.metadata.clone(),
+                time,
             }
         } else {
             // For files
             Stat {
-                path: path.join(&entry_name),
+                path: entry_path,
                 #[allow(clippy::unnecessary_cast)]
                 size: entry_stat.st_size as u64,
                 #[allow(clippy::unnecessary_cast)]
@@ -468,6 +512,7 @@ fn safe_du(
                 inodes: 1,
                 inode: file_info,
                 metadata: my_stat.metadata.clone(),
+                time,
             }
         };
 
@@ -521,19 +566,23 @@ fn safe_du(
                 my_stat.size += this_stat.size;
                 my_stat.blocks += this_stat.blocks;
                 my_stat.inodes += this_stat.inodes;
+                my_stat.time = my_stat.time.max(this_stat.time);
             }
             print_tx.send(Ok(StatPrintInfo {
                 stat: this_stat,
                 depth: depth + 1,
+                is_dir: true,
             }))?;
         } else {
             my_stat.size += this_stat.size;
             my_stat.blocks += this_stat.blocks;
             my_stat.inodes += 1;
+            my_stat.time = my_stat.time.max(this_stat.time);
             if options.all {
                 print_tx.send(Ok(StatPrintInfo {
                     stat: this_stat,
                     depth: depth + 1,
+                    is_dir: false,
                 }))?;
             }
         }
@@ -682,19 +731,23 @@ fn du_regular(
                                     my_stat.size += this_stat.size;
                                     my_stat.blocks += this_stat.blocks;
                                     my_stat.inodes += this_stat.inodes;
+                                    my_stat.time = my_stat.time.max(this_stat.time);
                                 }
                                 print_tx.send(Ok(StatPrintInfo {
                                     stat: this_stat,
                                     depth: depth + 1,
+                                    is_dir: true,
                                 }))?;
                             } else {
                                 my_stat.size += this_stat.size;
                                 my_stat.blocks += this_stat.blocks;
                                 my_stat.inodes += 1;
+                                my_stat.time = my_stat.time.max(this_stat.time);
                                 if options.all {
                                     print_tx.send(Ok(StatPrintInfo {
                                         stat: this_stat,
                                         depth: depth + 1,
+                                        is_dir: false,
                                     }))?;
                                 }
                             }
@@ -777,6 +830,7 @@ fn build_exclude_patterns(matches: &ArgMatches) -> UResult<Vec<Pattern>> {
 struct StatPrintInfo {
     stat: Stat,
     depth: usize,
+    is_dir: bool,
 }
 
 impl StatPrinter {
@@ -794,6 +848,7 @@ impl StatPrinter {
 
     fn print_stats(&self, rx: &mpsc::Receiver<UResult<StatPrintInfo>>) -> UResult<()> {
         let mut grand_total = 0;
+        let mut grand_time = None;
         loop {
             let received = rx.recv();
 
@@ -802,8 +857,11 @@ impl StatPrinter {
                     Ok(stat_info) => {
                         let size = self.choose_size(&stat_info.stat);
 
-                        if stat_info.depth == 0 {
+                        // With -S a directory's figures leave out its
+                        // subdirectories, so the total adds up all of them.
+                        if stat_info.depth == 0 || (self.separate_dirs && stat_info.is_dir) {
                             grand_total += size;
+                            grand_time = grand_time.max(stat_info.stat.time);
                         }
 
                         if !self
@@ -824,13 +882,9 @@ impl StatPrinter {
         }
 
         if self.total {
-            write!(
-                stdout(),
-                "{}\t{}{}",
-                self.convert_size(grand_total),
-                self.total_text,
-                self.line_ending
-            )?;
+            write!(stdout(), "{}\t", self.convert_size(grand_total))?;
+            self.print_time(grand_time)?;
+            write!(stdout(), "{}{}", self.total_text, self.line_ending)?;
         }
 
         Ok(())
@@ -857,23 +911,27 @@ impl StatPrinter {
         }
     }
 
-    fn print_stat(&self, stat: &Stat, size: u64) -> UResult<()> {
-        write!(stdout(), "{}\t", self.convert_size(size))?;
-
-        if let Some(md_time) = &self.time {
-            if let Some(time) = metadata_get_time(&stat.metadata, *md_time) {
-                format_system_time(
-                    &mut stdout(),
-                    time,
-                    &self.time_format,
-                    FormatSystemTimeFallback::IntegerError,
-                )?;
-                write!(stdout(), "\t")?;
-            } else {
-                write!(stdout(), "???\t")?;
-            }
+    fn print_time(&self, time: Option<SystemTime>) -> UResult<()> {
+        if self.time.is_none() {
+            return Ok(());
         }
+        if let Some(time) = time {
+            format_system_time(
+                &mut stdout(),
+                time,
+                &self.time_format,
+                FormatSystemTimeFallback::IntegerError,
+            )?;
+            write!(stdout(), "\t")?;
+        } else {
+            write!(stdout(), "???\t")?;
+        }
+        Ok(())
+    }
 
+    fn print_stat(&self, stat: &Stat, size: u64) -> UResult<()> {
+        write!(stdout(), "{}\t", self.convert_size(size))?;
+        self.print_time(stat.time)?;
         print_verbatim(&stat.path)?;
         write!(stdout(), "{}", self.line_ending)?;
 
@@ -1053,8 +1111,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         count_links,
         verbose: matches.get_flag(options::VERBOSE),
         excludes: build_exclude_patterns(&matches)?,
-        #[cfg(all(unix, not(target_os = "redox")))]
-        report_time: time.is_some(),
+        time,
     };
 
     let time_format = if time.is_some() {
@@ -1068,6 +1125,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         size_format,
         summarize,
         total: matches.get_flag(options::TOTAL),
+        separate_dirs: matches.get_flag(options::SEPARATE_DIRS),
         inodes: matches.get_flag(options::INODES),
         threshold: matches
             .get_one::<String>(options::THRESHOLD)
@@ -1149,8 +1207,13 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
                     Some(stat),
                 ) {
                     Ok(stat) => {
+                        let is_dir = stat.metadata.is_dir();
                         print_tx
-                            .send(Ok(StatPrintInfo { stat, depth: 0 }))
+                            .send(Ok(StatPrintInfo {
+                                stat,
+                                depth: 0,
+                                is_dir,
+                            }))
                             .map_err(|e| USimpleError::new(1, e.to_string()))?;
                     }
                     Err(e) => {
@@ -1179,8 +1242,13 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
                 )
                 .map_err(|e| USimpleError::new(1, e.to_string()))?;
 
+                let is_dir = stat.metadata.is_dir();
                 print_tx
-                    .send(Ok(StatPrintInfo { stat, depth: 0 }))
+                    .send(Ok(StatPrintInfo {
+                        stat,
+                        depth: 0,
+                        is_dir,
+                    }))
                     .map_err(|e| USimpleError::new(1, e.to_string()))?;
             } else {
                 #[cfg(unix)]
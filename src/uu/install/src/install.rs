@@ -795,9 +795,10 @@ fn standard(mut paths: Vec<OsString>, b: &Behavior) -> UResult<()> {
                     );
                 }
 
+                let timestamps = source_timestamps(source, b)?;
                 copy_file_safe(source, parent_fd, filename.as_os_str())?;
 
-                finalize_installed_file(source, &target, b, backup_path)
+                finalize_installed_file(source, &target, b, backup_path, timestamps)
             } else {
                 copy(source, &target, b)
             }
@@ -1082,27 +1083,44 @@ fn set_ownership_and_permissions(to: &Path, b: &Behavior) -> UResult<()> {
     Ok(())
 }
 
-/// Preserve timestamps on the destination file.
+/// Read the source timestamps to preserve, if requested.
+///
+/// This must happen before copying, since reading the source may update its
+/// access time.
 ///
 /// # Parameters
 ///
 /// * `from` - The source file path.
-/// * `to` - The destination file path.
+/// * `b` - The install behavior.
 ///
 /// # Returns
 ///
-/// Returns an empty Result or an error in case of failure.
+/// Returns the access and modification times, or `None` without `-p`.
 ///
-fn preserve_timestamps(from: &Path, to: &Path) -> UResult<()> {
+fn source_timestamps(from: &Path, b: &Behavior) -> UResult<Option<(FileTime, FileTime)>> {
+    if !b.preserve_timestamps {
+        return Ok(None);
+    }
     let meta = metadata(from).map_err(InstallError::MetadataFailed)?;
-    let modified_time = FileTime::from_last_modification_time(&meta);
-    let accessed_time = FileTime::from_last_access_time(&meta);
+    Ok(Some((
+        FileTime::from_last_access_time(&meta),
+        FileTime::from_last_modification_time(&meta),
+    )))
+}
 
+/// Preserve timestamps on the destination file.
+///
+/// Failures are reported but otherwise ignored.
+///
+/// # Parameters
+///
+/// * `to` - The destination file path.
+/// * `(accessed_time, modified_time)` - The source timestamps.
+///
+fn preserve_timestamps(to: &Path, (accessed_time, modified_time): (FileTime, FileTime)) {
     if let Err(e) = set_file_times(to, accessed_time, modified_time) {
         show_error!("{e}");
-        // ignore error
     }
-    Ok(())
 }
 
 /// Apply post-copy operations: strip, ownership, permissions, timestamps, SELinux, and verbose output.
@@ -1111,6 +1129,7 @@ fn finalize_installed_file(
     to: &Path,
     b: &Behavior,
     backup_path: Option<PathBuf>,
+    timestamps: Option<(FileTime, FileTime)>,
 ) -> UResult<()> {
     if b.strip {
         strip_file(to, b)?;
@@ -1118,8 +1137,8 @@ fn finalize_installed_file(
 
     set_ownership_and_permissions(to, b)?;
 
-    if b.preserve_timestamps {
-        preserve_timestamps(from, to)?;
+    if let Some(timestamps) = timestamps {
+        preserve_timestamps(to, timestamps);
     }
 
     #[cfg(all(feature = "selinux", any(target_os = "linux", target_os = "android")))]
@@ -1172,9 +1191,10 @@ fn copy(from: &Path, to: &Path, b: &Behavior) -> UResult<()> {
     // Declare the path here as we may need it for the verbose output below.
     let backup_path = perform_backup(to, b)?;
 
+    let timestamps = source_timestamps(from, b)?;
     copy_file(from, to)?;
 
-    finalize_installed_file(from, to, b, backup_path)
+    finalize_installed_file(from, to, b, backup_path, timestamps)
 }
 
 #[cfg(all(feature = "selinux", any(target_os = "linux", target_os = "android")))]
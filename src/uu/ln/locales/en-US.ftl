@@ -12,6 +12,8 @@ ln-after-help = In the 1st form, create a link to TARGET with the name LINK_NAME
   can hold arbitrary text; if later resolved, a relative link is
   interpreted in relation to its parent directory.
 
+ln-help-directory = allow the superuser to attempt to hard link directories
+  (note: will probably fail due to system restrictions, even for the superuser)
 ln-help-force = remove existing destination files
 ln-help-interactive = prompt whether to remove existing destination files
 ln-help-no-dereference = treat LINK_NAME as a normal file if it is a
@@ -33,6 +35,6 @@ ln-error-will-not-overwrite = will not overwrite just-created {$target} with {$s
 ln-prompt-replace = replace {$file}?
 ln-cannot-backup = cannot backup {$file}
 ln-failed-to-access = failed to access {$file}
-ln-failed-to-create-hard-link = failed to create hard link {$source} => {$dest}
+ln-failed-to-create-hard-link = failed to create hard link {$dest} => {$source}
 ln-failed-to-create-hard-link-dir = {$source}: hard link not allowed for directory
 ln-backup = backup: {$backup}
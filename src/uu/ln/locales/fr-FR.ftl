@@ -12,6 +12,8 @@ ln-after-help = Dans la 1ère forme, créer un lien vers CIBLE avec le nom NOM_L
   peuvent contenir du texte arbitraire ; s'ils sont résolus plus tard, un lien relatif est
   interprété en relation avec son répertoire parent.
 
+ln-help-directory = permettre au superutilisateur de tenter de créer des liens physiques vers des répertoires
+  (note : échouera probablement à cause des restrictions du système, même pour le superutilisateur)
 ln-help-force = supprimer les fichiers de destination existants
 ln-help-interactive = demander avant de supprimer les fichiers de destination existants
 ln-help-no-dereference = traiter NOM_LIEN comme un fichier normal s'il s'agit d'un
@@ -34,6 +36,6 @@ ln-error-will-not-overwrite = ne remplacera pas le fichier {$target} qui vient d
 ln-prompt-replace = remplacer {$file} ?
 ln-cannot-backup = impossible de sauvegarder {$file}
 ln-failed-to-access = échec d'accès à {$file}
-ln-failed-to-create-hard-link = échec de création du lien physique {$source} => {$dest}
+ln-failed-to-create-hard-link = échec de création du lien physique {$dest} => {$source}
 ln-failed-to-create-hard-link-dir = {$source} : lien physique non autorisé pour un répertoire
 ln-backup = sauvegarde : {$backup}
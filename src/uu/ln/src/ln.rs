@@ -8,7 +8,7 @@
 use clap::{Arg, ArgAction, Command};
 use std::io::{self, Write, stdout};
 use uucore::display::Quotable;
-use uucore::error::{UError, UIoError, UResult};
+use uucore::error::{UError, UIoError, UResult, strip_errno};
 
 use uucore::fs::{make_path_relative_to, paths_refer_to_same_file};
 use uucore::translate;
@@ -35,6 +35,7 @@ pub struct Settings {
     pub backup: BackupMode,
     pub suffix: OsString,
     pub symbolic: bool,
+    pub directory: bool,
     pub relative: bool,
     pub logical: bool,
     pub target_dir: Option<PathBuf>,
@@ -61,6 +62,9 @@ pub enum LnError {
     #[error("{1}: {0}")]
     IoContext(UIoError, String),
 
+    #[error("{}: {}", _1, strip_errno(_0))]
+    OsErrorContext(io::Error, String),
+
     #[error("")]
     SomeLinksFailed,
 
@@ -92,7 +96,7 @@ impl From<io::Error> for LnError {
 
 mod options {
     pub const FORCE: &str = "force";
-    //pub const DIRECTORY: &str = "directory";
+    pub const DIRECTORY: &str = "directory";
     pub const INTERACTIVE: &str = "interactive";
     pub const NO_DEREFERENCE: &str = "no-dereference";
     pub const SYMBOLIC: &str = "symbolic";
@@ -140,6 +144,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         backup: backup_mode,
         suffix: OsString::from(backup_suffix),
         symbolic,
+        directory: matches.get_flag(options::DIRECTORY),
         logical,
         relative: matches.get_flag(options::RELATIVE),
         target_dir: matches
@@ -170,12 +175,14 @@ pub fn uu_app() -> Command {
         .after_help(after_help)
         .arg(backup_control::arguments::backup())
         .arg(backup_control::arguments::backup_no_args())
-        /*.arg(
+        .arg(
             Arg::new(options::DIRECTORY)
                 .short('d')
+                .short_alias('F')
                 .long(options::DIRECTORY)
-                .help("allow users with appropriate privileges to attempt to make hard links to directories")
-        )*/
+                .help(translate!("ln-help-directory"))
+                .action(ArgAction::SetTrue),
+        )
         .arg(
             Arg::new(options::FORCE)
                 .short('f')
@@ -471,17 +478,27 @@ fn link(src: &Path, dst: &Path, settings: &Settings) -> LnResult<()> {
         } else {
             source.to_path_buf()
         };
+        let context = || {
+            translate!(
+                "ln-failed-to-create-hard-link",
+                "source" => source.quote(),
+                "dest" => dst.quote()
+            )
+        };
         match fs::hard_link(&p, dst) {
             Ok(()) => Ok(()),
-            Err(_) if p.is_dir() => Err(LnError::FailedToCreateHardLinkDir(source.to_path_buf())),
-            Err(e) => Err(LnError::IoContext(
-                UIoError::from(e),
-                translate!(
-                    "ln-failed-to-create-hard-link",
-                    "source" => source.quote(),
-                    "dest" => dst.quote()
-                ),
-            )),
+            // With -d the kernel decides, so report its error instead.
+            Err(_) if !settings.directory && p.is_dir() => {
+                Err(LnError::FailedToCreateHardLinkDir(source.to_path_buf()))
+            }
+            // `UIoError` would show the EPERM that link() gives for a
+            // directory as "Permission denied", so keep the system's
+            // message to tell it apart from EACCES.
+            #[cfg(unix)]
+            Err(e) if e.raw_os_error() == Some(uucore::libc::EPERM) => {
+                Err(LnError::OsErrorContext(e, context()))
+            }
+            Err(e) => Err(LnError::IoContext(UIoError::from(e), context())),
         }
     };
 
@@ -324,8 +324,8 @@ fn display_dir_entry_size(
         let nlink_len = display_symlink_count(md).len();
         (
             nlink_len,
-            display_uname(md, config, &mut state.uid_cache).len(),
-            display_group(md, config, &mut state.gid_cache).len(),
+            display_uname(md, config, &mut state.uid_cache).0.len(),
+            display_group(md, config, &mut state.gid_cache).0.len(),
             size_len,
             major_len,
             minor_len,
@@ -363,6 +363,16 @@ impl ExtendPad for Vec<u8> {
     }
 }
 
+/// Pad an owner, group or author column. Like GNU, IDs printed as numbers
+/// (with `-n`, or when there is no matching name) are right-aligned.
+fn extend_pad_name(buf: &mut Vec<u8>, (name, numeric): (&str, bool), count: usize) {
+    if numeric {
+        buf.extend_pad_left(name, count);
+    } else {
+        buf.extend_pad_right(name, count);
+    }
+}
+
 // TODO: Consider converting callers to use ExtendPad instead, as it avoids
 // additional copies.
 fn pad_left(string: &str, count: usize) -> String {
@@ -633,47 +643,59 @@ fn display_additional_leading_info(
 
 // Currently getpwuid is `linux` target only. If it's broken state.out into
 // a posix-compliant attribute this can be updated...
+//
+// Along with the name, these return whether it is the ID printed as a number.
 #[cfg(unix)]
 fn display_uname<'a>(
     metadata: &Metadata,
     config: &Config,
-    uid_cache: &'a mut FxHashMap<u32, String>,
-) -> &'a String {
+    uid_cache: &'a mut FxHashMap<u32, (String, bool)>,
+) -> (&'a str, bool) {
     let uid = metadata.uid();
 
-    uid_cache.entry(uid).or_insert_with(|| {
+    let (name, numeric) = uid_cache.entry(uid).or_insert_with(|| {
         if config.long.numeric_uid_gid {
-            uid.to_string()
+            (uid.to_string(), true)
         } else {
-            entries::uid2usr(uid).unwrap_or_else(|_| uid.to_string())
+            entries::uid2usr(uid).map_or_else(|_| (uid.to_string(), true), |name| (name, false))
         }
-    })
+    });
+    (name, *numeric)
 }
 
 #[cfg(unix)]
 fn display_group<'a>(
     metadata: &Metadata,
     config: &Config,
-    gid_cache: &'a mut FxHashMap<u32, String>,
-) -> &'a String {
+    gid_cache: &'a mut FxHashMap<u32, (String, bool)>,
+) -> (&'a str, bool) {
     let gid = metadata.gid();
-    gid_cache.entry(gid).or_insert_with(|| {
+    let (name, numeric) = gid_cache.entry(gid).or_insert_with(|| {
         if config.long.numeric_uid_gid {
-            gid.to_string()
+            (gid.to_string(), true)
         } else {
-            entries::gid2grp(gid).unwrap_or_else(|_| gid.to_string())
+            entries::gid2grp(gid).map_or_else(|_| (gid.to_string(), true), |name| (name, false))
         }
-    })
+    });
+    (name, *numeric)
 }
 
 #[cfg(not(unix))]
-fn display_uname(_metadata: &Metadata, _config: &Config, _uid_cache: &mut ()) -> &'static str {
-    "somebody"
+fn display_uname(
+    _metadata: &Metadata,
+    _config: &Config,
+    _uid_cache: &mut (),
+) -> (&'static str, bool) {
+    ("somebody", false)
 }
 
 #[cfg(not(unix))]
-fn display_group(_metadata: &Metadata, _config: &Config, _gid_cache: &mut ()) -> &'static str {
-    "somegroup"
+fn display_group(
+    _metadata: &Metadata,
+    _config: &Config,
+    _gid_cache: &mut (),
+) -> (&'static str, bool) {
+    ("somegroup", false)
 }
 
 fn display_date(
@@ -978,7 +1000,8 @@ fn display_item_long(
 
         if config.long.owner {
             state.display_buf.push(b' ');
-            state.display_buf.extend_pad_right(
+            extend_pad_name(
+                &mut state.display_buf,
                 display_uname(md, config, &mut state.uid_cache),
                 padding.uname,
             );
@@ -986,7 +1009,8 @@ fn display_item_long(
 
         if config.long.group {
             state.display_buf.push(b' ');
-            state.display_buf.extend_pad_right(
+            extend_pad_name(
+                &mut state.display_buf,
                 display_group(md, config, &mut state.gid_cache),
                 padding.group,
             );
@@ -1003,7 +1027,8 @@ fn display_item_long(
         // the owner, since GNU/Hurd is not currently supported by Rust.
         if config.long.author {
             state.display_buf.push(b' ');
-            state.display_buf.extend_pad_right(
+            extend_pad_name(
+                &mut state.display_buf,
                 display_uname(md, config, &mut state.uid_cache),
                 padding.uname,
             );
@@ -1023,10 +1023,11 @@ struct ListState<'a> {
     // number of users/groups is very limited. It seems like nohash::IntMap
     // performance was equivalent to BTreeMap.
     // It's possible a simple vector linear(binary?) search implementation would be even faster.
+    // Each name is cached with whether it is the ID printed as a number.
     #[cfg(unix)]
-    uid_cache: FxHashMap<u32, String>,
+    uid_cache: FxHashMap<u32, (String, bool)>,
     #[cfg(unix)]
-    gid_cache: FxHashMap<u32, String>,
+    gid_cache: FxHashMap<u32, (String, bool)>,
     #[cfg(not(unix))]
     uid_cache: (),
     #[cfg(not(unix))]
@@ -1520,7 +1521,8 @@ fn sort_entries(entries: &mut [PathData], config: &Config) {
         Sort::None => {}
     }
 
-    if config.reverse {
+    // Like GNU, -r only reverses an actual sort, so -U keeps directory order.
+    if config.reverse && config.sort != Sort::None {
         entries.reverse();
     }
 
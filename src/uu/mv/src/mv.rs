@@ -773,8 +773,10 @@ fn rename(
 
     #[cfg(all(feature = "selinux", any(target_os = "linux", target_os = "android")))]
     if let Some(ref context) = opts.context {
-        set_selinux_security_context(to, Some(context))
-            .map_err(|e| io::Error::other(e.to_string()))?;
+        // This runs after both a rename and a cross-device copy. `-Z` leaves
+        // the context empty, which asks for the default one of the new location.
+        let context = Some(context).filter(|c| !c.is_empty());
+        set_selinux_security_context(to, context).map_err(|e| io::Error::other(e.to_string()))?;
     }
 
     if opts.verbose {
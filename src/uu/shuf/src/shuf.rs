@@ -7,7 +7,7 @@
 
 use std::ffi::{OsStr, OsString};
 use std::fs::File;
-use std::io::{self, BufReader, BufWriter, Read, Write, stdin, stdout};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write, stdin, stdout};
 use std::ops::RangeInclusive;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
@@ -40,6 +40,10 @@ enum Mode {
 
 const BUF_SIZE: usize = 64 * 1024;
 
+/// With --head-count, inputs larger than this (or of unknown size, like pipes)
+/// are sampled while reading instead of being kept in memory, like GNU.
+const RESERVOIR_MIN_INPUT: u64 = 8 * 1024 * 1024;
+
 struct Options {
     head_count: u64,
     output: Option<PathBuf>,
@@ -151,7 +155,7 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     };
 
     let fdata = match mode {
-        Mode::Default(ref filename) => Some(read_input_file(filename)?),
+        Mode::Default(ref filename) => Some(read_input(filename, &options, &mut rng)?),
         _ => None,
     };
 
@@ -165,11 +169,17 @@ pub fn uumain(args: impl uucore::Args) -> UResult<()> {
         Mode::InputRange(mut range) => {
             shuf_exec(&mut range, &options, &mut rng, &mut output)?;
         }
-        Mode::Default(_) => {
-            let fdata = fdata.unwrap_or_default();
-            let mut items = split_seps(&fdata, options.sep);
-            shuf_exec(&mut items, &options, &mut rng, &mut output)?;
-        }
+        Mode::Default(_) => match fdata {
+            Some(Input::Sampled(lines)) => {
+                let mut items: Vec<&[u8]> = lines.iter().map(Vec::as_slice).collect();
+                shuf_exec(&mut items, &options, &mut rng, &mut output)?;
+            }
+            Some(Input::Whole(fdata)) => {
+                let mut items = split_seps(&fdata, options.sep);
+                shuf_exec(&mut items, &options, &mut rng, &mut output)?;
+            }
+            None => {}
+        },
     }
 
     Ok(())
@@ -272,16 +282,119 @@ fn open_output(output: Option<&Path>) -> UResult<BufWriter<Box<dyn OsWrite>>> {
     Ok(BufWriter::with_capacity(BUF_SIZE, writer))
 }
 
-fn read_input_file(filename: &Path) -> UResult<Vec<u8>> {
+enum Input {
+    /// The entire input, still to be split into lines.
+    Whole(Vec<u8>),
+    /// A uniform random sample of at most --head-count lines.
+    Sampled(Vec<Vec<u8>>),
+}
+
+fn read_input(filename: &Path, opts: &Options, rng: &mut WrappedRng) -> UResult<Input> {
+    // Seeded output is documented as a Fisher-Yates shuffle of the whole
+    // input, so it never samples to stay reproducible.
+    let may_sample = !opts.repeat
+        && opts.head_count != u64::MAX
+        && !matches!(opts.random_source, RandomSource::Seed(_));
+
     if filename.as_os_str() == "-" {
+        let context = || translate!("shuf-error-read-error");
+        let stdin = stdin();
+        if may_sample && !is_small_input(stdin_file().as_ref()) {
+            return reservoir_sample(stdin.lock(), opts, rng, context).map(Input::Sampled);
+        }
         let mut data = Vec::new();
-        stdin()
+        stdin
+            .lock()
             .read_to_end(&mut data)
-            .map_err_context(|| translate!("shuf-error-read-error"))?;
-        Ok(data)
+            .map_err_context(context)?;
+        Ok(Input::Whole(data))
     } else {
-        std::fs::read(filename).map_err_context(|| filename.maybe_quote().to_string())
+        let context = || filename.maybe_quote().to_string();
+        let mut file = File::open(filename).map_err_context(context)?;
+        if may_sample && !is_small_input(Some(&file)) {
+            let reader = BufReader::with_capacity(BUF_SIZE, file);
+            return reservoir_sample(reader, opts, rng, context).map(Input::Sampled);
+        }
+        let mut data = Vec::new();
+        file.read_to_end(&mut data).map_err_context(context)?;
+        Ok(Input::Whole(data))
+    }
+}
+
+/// Returns a handle on standard input for inspecting its size.
+#[cfg(any(unix, target_os = "wasi"))]
+fn stdin_file() -> Option<File> {
+    use std::os::fd::AsFd;
+    stdin().as_fd().try_clone_to_owned().ok().map(File::from)
+}
+
+#[cfg(not(any(unix, target_os = "wasi")))]
+fn stdin_file() -> Option<File> {
+    None
+}
+
+/// Whether the input is a regular file with at most [`RESERVOIR_MIN_INPUT`]
+/// bytes left to read.
+fn is_small_input(file: Option<&File>) -> bool {
+    use std::io::Seek;
+    let Some(mut file) = file else {
+        return false;
+    };
+    let (Ok(metadata), Ok(offset)) = (file.metadata(), file.stream_position()) else {
+        return false;
+    };
+    metadata.is_file() && metadata.len().saturating_sub(offset) <= RESERVOIR_MIN_INPUT
+}
+
+/// Reservoir sampling: keeps the first --head-count lines, then lets each later
+/// line replace a random one with decreasing probability, so that memory use
+/// is bounded by the sample rather than the input.
+fn reservoir_sample(
+    mut reader: impl BufRead,
+    opts: &Options,
+    rng: &mut WrappedRng,
+    context: impl Fn() -> String,
+) -> UResult<Vec<Vec<u8>>> {
+    let amount = usize::try_from(opts.head_count).unwrap_or(usize::MAX);
+    let mut reservoir = Vec::new();
+    let mut line = Vec::new();
+
+    while reservoir.len() < amount {
+        if !read_line(&mut reader, opts.sep, &mut line).map_err_context(&context)? {
+            return Ok(reservoir);
+        }
+        reservoir.push(std::mem::take(&mut line));
+    }
+
+    // Like GNU, the slot is drawn before the line is read, so one more draw is
+    // made at the end of the input. This keeps --random-source compatible.
+    let mut seen = reservoir.len() as u64;
+    loop {
+        let slot = rng.choose_from_range(0..=seen)?;
+        if !read_line(&mut reader, opts.sep, &mut line).map_err_context(&context)? {
+            return Ok(reservoir);
+        }
+        if let Some(kept) = usize::try_from(slot)
+            .ok()
+            .and_then(|i| reservoir.get_mut(i))
+        {
+            std::mem::swap(kept, &mut line);
+        }
+        seen += 1;
+    }
+}
+
+/// Reads the next line into `line` without its separator.
+/// Returns `false` at the end of the input.
+fn read_line(reader: &mut impl BufRead, sep: u8, line: &mut Vec<u8>) -> io::Result<bool> {
+    line.clear();
+    if reader.read_until(sep, line)? == 0 {
+        return Ok(false);
+    }
+    if line.last() == Some(&sep) {
+        line.pop();
     }
+    Ok(true)
 }
 
 fn split_seps(data: &[u8], sep: u8) -> Vec<&[u8]> {
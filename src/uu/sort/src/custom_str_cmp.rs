@@ -57,3 +57,24 @@ pub fn custom_str_cmp(
         }
     }
 }
+
+/// Returns `s` with the characters ignored by the given settings removed and,
+/// with `ignore_case`, folded to upper case, ready for locale collation.
+#[cfg(feature = "i18n-collator")]
+pub fn custom_str_key(
+    s: &[u8],
+    ignore_non_printing: bool,
+    ignore_non_dictionary: bool,
+    ignore_case: bool,
+) -> Vec<u8> {
+    s.iter()
+        .filter(|&&c| filter_char(c, ignore_non_printing, ignore_non_dictionary))
+        .map(|&c| {
+            if ignore_case {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect()
+}
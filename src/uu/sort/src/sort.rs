@@ -24,6 +24,8 @@ use chunks::LineData;
 use clap::builder::ValueParser;
 use clap::{Arg, ArgAction, ArgMatches, Command};
 use custom_str_cmp::custom_str_cmp;
+#[cfg(feature = "i18n-collator")]
+use custom_str_cmp::custom_str_key;
 use ext_sort::ext_sort;
 use foldhash::fast::FoldHasher;
 use foldhash::{HashMap, SharedSeed};
@@ -327,6 +329,7 @@ struct Precomputed {
     fast_lexicographic: bool,
     fast_locale_collation: bool,
     fast_ascii_insensitive: bool,
+    locale_collation: bool,
     tokenize_blank_thousands_sep: bool,
     tokenize_allow_unit_after_blank: bool,
 }
@@ -391,7 +394,9 @@ impl GlobalSettings {
             !disable_fast_lexicographic && self.can_use_fast_lexicographic();
         self.precomputed.fast_locale_collation =
             disable_fast_lexicographic && self.can_use_fast_lexicographic();
-        self.precomputed.fast_ascii_insensitive = self.can_use_fast_ascii_insensitive();
+        self.precomputed.fast_ascii_insensitive =
+            !disable_fast_lexicographic && self.can_use_fast_ascii_insensitive();
+        self.precomputed.locale_collation = disable_fast_lexicographic;
     }
 
     /// Returns true when the fast lexicographic path can be used safely.
@@ -2730,10 +2735,21 @@ fn compare_by<'a>(
                 // Use locale-aware comparison if feature is enabled and no custom flags are set
                 #[cfg(feature = "i18n-collator")]
                 {
-                    if settings.ignore_case
+                    let has_custom_flags = settings.ignore_case
                         || settings.dictionary_order
-                        || settings.ignore_non_printing
-                    {
+                        || settings.ignore_non_printing;
+                    if has_custom_flags && global_settings.precomputed.locale_collation {
+                        // Like GNU, filter and fold the keys first, then collate them.
+                        let key = |s| {
+                            custom_str_key(
+                                s,
+                                settings.ignore_non_printing,
+                                settings.dictionary_order,
+                                settings.ignore_case,
+                            )
+                        };
+                        locale_cmp(&key(a_str), &key(b_str))
+                    } else if has_custom_flags {
                         custom_str_cmp(
                             a_str,
                             b_str,
@@ -2769,6 +2785,13 @@ fn compare_by<'a>(
     {
         Ordering::Equal
     } else {
+        #[cfg(feature = "i18n-collator")]
+        if global_settings.precomputed.locale_collation {
+            locale_cmp(a.line, b.line)
+        } else {
+            a.line.cmp(b.line)
+        }
+        #[cfg(not(feature = "i18n-collator"))]
         a.line.cmp(b.line)
     };
 
@@ -2805,8 +2828,8 @@ fn ascii_case_insensitive_cmp(a: &[u8], b: &[u8]) -> Ordering {
 // For example, 5e10KFD would be 5e10 or 5x10^10 and +10000HFKJFK would become 10000.
 #[allow(clippy::cognitive_complexity)]
 fn get_leading_gen(inp: &[u8], decimal_pt: u8) -> Range<usize> {
-    // check for inf, -inf and nan
-    const ALLOWED_PREFIXES: &[&[u8]] = &[b"inf", b"-inf", b"nan"];
+    // check for signed or unsigned inf and nan
+    const ALLOWED_PREFIXES: &[&[u8]] = &[b"inf", b"-inf", b"+inf", b"nan", b"-nan", b"+nan"];
 
     let trimmed = inp.trim_ascii_start();
     let leading_whitespace_len = inp.len() - trimmed.len();
@@ -2878,6 +2901,8 @@ fn get_leading_gen(inp: &[u8], decimal_pt: u8) -> Range<usize> {
 pub enum GeneralBigDecimalParseResult {
     Invalid,
     Nan,
+    /// GNU compares NaNs by their bit patterns, which puts `-nan` after `nan`.
+    MinusNan,
     MinusInfinity,
     Number(BigDecimal),
     Infinity,
@@ -2916,7 +2941,8 @@ fn general_bd_parse(a: &[u8], decimal_pt: u8) -> GeneralBigDecimalParseResult {
         ExtendedBigDecimal::MinusInfinity => GeneralBigDecimalParseResult::MinusInfinity,
         // Minus zero and zero are equal
         ExtendedBigDecimal::MinusZero => GeneralBigDecimalParseResult::Number(0.into()),
-        ExtendedBigDecimal::Nan | ExtendedBigDecimal::MinusNan => GeneralBigDecimalParseResult::Nan,
+        ExtendedBigDecimal::Nan => GeneralBigDecimalParseResult::Nan,
+        ExtendedBigDecimal::MinusNan => GeneralBigDecimalParseResult::MinusNan,
     }
 }
 
@@ -1040,10 +1040,18 @@ fn n_chunks_by_line(
     // In N chunks mode - we will write to `num_chunks` files
     let mut out_files: OutFiles = OutFiles::new();
 
-    // Calculate chunk size base and modulo reminder
-    // to be used in calculating `num_bytes_should_be_written` later on
-    let chunk_size_base = num_bytes / num_chunks;
-    let chunk_size_reminder = num_bytes % num_chunks;
+    // As in GNU split, every chunk but the last is `chunk_size` bytes long
+    // (at least one byte, even for inputs shorter than `num_chunks`), and the
+    // last one also takes the remainder. A chunk ends with the first line that
+    // reaches its nominal end, so lines are never broken.
+    let chunk_size = (num_bytes / num_chunks).max(1);
+    let chunk_end = |chunk_number: u64| {
+        if chunk_number == num_chunks {
+            num_bytes
+        } else {
+            chunk_number * chunk_size
+        }
+    };
 
     // If in N chunks mode
     // Generate filenames for each file and
@@ -1057,18 +1065,13 @@ fn n_chunks_by_line(
     }
 
     let mut chunk_number = 1;
-    let sep = settings.separator;
-    let mut num_bytes_should_be_written = chunk_size_base + (chunk_size_reminder > 0) as u64;
+    // Index of the output file for the current chunk. It only differs from
+    // `chunk_number - 1` when empty chunks are elided.
+    let mut file_idx = 0;
     let mut num_bytes_written = 0;
 
-    for line_result in reader.split(sep) {
-        let mut line = line_result?;
-        // add separator back in at the end of the line,
-        // since `reader.split(sep)` removes it,
-        // except if the last line did not end with separator character
-        if (num_bytes_written + line.len() as u64) < num_bytes {
-            line.push(sep);
-        }
+    for line_result in lines_with_sep(reader, settings.separator) {
+        let line = line_result?;
         let bytes = line.as_slice();
 
         if let Some(kth) = kth_chunk {
@@ -1076,33 +1079,24 @@ fn n_chunks_by_line(
                 stdout_writer.write_all(bytes)?;
             }
         } else {
-            // Should write into a file
-            let idx = (chunk_number - 1) as usize;
-            let writer = out_files.get_writer(idx, settings)?;
+            let writer = out_files.get_writer(file_idx, settings)?;
             custom_write_all(bytes, writer, settings)?;
         }
+        num_bytes_written += bytes.len() as u64;
 
         // Advance to the next chunk if the current one is filled.
-        // There could be a situation when a long line, which started in current chunk,
-        // would overlap the next chunk (or even several next chunks),
-        // and since we cannot break lines for this split strategy, we could end up with
-        // empty files in place(s) of skipped chunk(s)
-        let num_line_bytes = bytes.len() as u64;
-        num_bytes_written += num_line_bytes;
-        let mut skipped = -1;
-        while num_bytes_should_be_written <= num_bytes_written {
-            num_bytes_should_be_written +=
-                chunk_size_base + (chunk_size_reminder > chunk_number) as u64;
+        // A long line, which started in the current chunk, can reach past
+        // the end of the next chunk (or even several next chunks). Those
+        // chunks are skipped and end up as empty files.
+        if num_bytes_written >= chunk_end(chunk_number) && chunk_number < num_chunks {
+            file_idx += 1;
             chunk_number += 1;
-            skipped += 1;
-        }
-
-        // If a chunk was skipped and `elide_empty_files` flag is set,
-        // roll chunk_number back to preserve sequential continuity
-        // of file names for files written to,
-        // except for Kth chunk of N mode
-        if settings.elide_empty_files && skipped > 0 && kth_chunk.is_none() {
-            chunk_number -= skipped as u64;
+            while num_bytes_written >= chunk_end(chunk_number) && chunk_number < num_chunks {
+                if !settings.elide_empty_files {
+                    file_idx += 1;
+                }
+                chunk_number += 1;
+            }
         }
         if kth_chunk.is_some_and(|k| chunk_number > k) {
             break;
@@ -74,6 +74,10 @@ stat-warning-unrecognized-escape = unrecognized escape '\{$escape}'
 
 ## Help messages
 
+stat-help-cached = specify how to use cached attributes; useful on remote file systems.
+  MODE can be: always (use cached attributes if available), never (try to
+  synchronize with the latest attributes), or default (leave it up to the
+  underlying file system)
 stat-help-dereference = follow links
 stat-help-file-system = display file system status instead of file status
 stat-help-terse = print the information in terse form
@@ -54,6 +54,10 @@ stat-after-help = Séquences de format valides pour les fichiers (sans `--file-s
 
 # Messages d'aide
 
+stat-help-cached = spécifier comment utiliser les attributs en cache ; utile sur les systèmes de fichiers distants.
+  MODE peut être : always (utiliser les attributs en cache si disponibles), never (essayer de
+  se synchroniser avec les derniers attributs) ou default (laisser le choix au
+  système de fichiers sous-jacent)
 stat-help-dereference = suivre les liens
 stat-help-file-system = afficher le statut du système de fichiers au lieu du statut du fichier
 stat-help-terse = afficher les informations en forme concise
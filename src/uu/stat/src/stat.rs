@@ -12,22 +12,24 @@ use uucore::translate;
 use clap::builder::ValueParser;
 use uucore::display::Quotable;
 use uucore::error::strip_errno;
-use uucore::fs::{display_permissions, major, minor};
+use uucore::fs::{display_permissions_unix, major, minor};
 use uucore::fsext::{
     FsMeta, MetadataTimeField, StatFs, metadata_get_time, pretty_filetype, pretty_fstype,
     read_fs_list, statfs,
 };
-use uucore::libc::mode_t;
+use uucore::libc::{S_IFBLK, S_IFCHR, S_IFLNK, S_IFMT, mode_t};
+use uucore::parser::shortcut_value_parser::ShortcutValueParser;
 use uucore::{entries, format_usage, show_error, show_warning};
 
 use clap::{Arg, ArgAction, ArgMatches, Command};
 use std::borrow::Cow;
 use std::cell::OnceCell;
 use std::ffi::{OsStr, OsString};
-use std::fs::{FileType, Metadata};
+use std::fs::Metadata;
 use std::io::Write;
 use std::os::unix::fs::{FileTypeExt, MetadataExt};
 use std::path::Path;
+use std::time::SystemTime;
 use std::{env, fs};
 
 use thiserror::Error;
@@ -58,6 +60,7 @@ impl UError for StatError {
 }
 
 mod options {
+    pub const CACHED: &str = "cached";
     pub const DEREFERENCE: &str = "dereference";
     pub const FILE_SYSTEM: &str = "file-system";
     pub const FORMAT: &str = "format";
@@ -66,6 +69,126 @@ mod options {
     pub const FILES: &str = "files";
 }
 
+/// How `--cached` asks the kernel to treat cached file attributes.
+#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+enum CachedMode {
+    /// Leave it up to the file system.
+    #[default]
+    Default,
+    /// Use cached attributes if available.
+    Always,
+    /// Synchronize with the latest attributes.
+    Never,
+}
+
+impl CachedMode {
+    fn from_arg(s: &str) -> Self {
+        match s {
+            "always" => Self::Always,
+            "never" => Self::Never,
+            _ => Self::Default,
+        }
+    }
+
+    /// The `AT_STATX_*` synchronization flag matching this mode.
+    #[cfg(target_os = "linux")]
+    fn statx_sync_flags(self) -> rustix::fs::AtFlags {
+        use rustix::fs::AtFlags;
+        match self {
+            Self::Default => AtFlags::empty(),
+            Self::Always => AtFlags::STATX_DONT_SYNC,
+            Self::Never => AtFlags::STATX_FORCE_SYNC,
+        }
+    }
+}
+
+/// The attributes of a file printed by `stat`, read either with `statx`
+/// or from [`Metadata`].
+struct FileAttributes {
+    mode: u32,
+    nlink: u64,
+    uid: u32,
+    gid: u32,
+    ino: u64,
+    size: u64,
+    blocks: u64,
+    blksize: u64,
+    dev: u64,
+    rdev: u64,
+    accessed: Option<SystemTime>,
+    modified: Option<SystemTime>,
+    changed: Option<SystemTime>,
+    born: Option<SystemTime>,
+}
+
+impl FileAttributes {
+    fn from_metadata(meta: &Metadata) -> Self {
+        Self {
+            mode: meta.mode(),
+            nlink: meta.nlink(),
+            uid: meta.uid(),
+            gid: meta.gid(),
+            ino: meta.ino(),
+            size: meta.len(),
+            blocks: meta.blocks(),
+            blksize: meta.blksize(),
+            dev: meta.dev(),
+            rdev: meta.rdev(),
+            accessed: metadata_get_time(meta, MetadataTimeField::Access),
+            modified: metadata_get_time(meta, MetadataTimeField::Modification),
+            changed: metadata_get_time(meta, MetadataTimeField::Change),
+            born: metadata_get_time(meta, MetadataTimeField::Birth),
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    fn from_statx(stx: &rustix::fs::Statx) -> Self {
+        use rustix::fs::{StatxFlags, StatxTimestamp, makedev};
+        use std::time::{Duration, UNIX_EPOCH};
+
+        fn to_system_time(ts: StatxTimestamp) -> Option<SystemTime> {
+            let secs = Duration::from_secs(ts.tv_sec.unsigned_abs());
+            let time = if ts.tv_sec < 0 {
+                UNIX_EPOCH.checked_sub(secs)?
+            } else {
+                UNIX_EPOCH.checked_add(secs)?
+            };
+            time.checked_add(Duration::from_nanos(ts.tv_nsec.into()))
+        }
+
+        let has_birth = StatxFlags::from_bits_retain(stx.stx_mask).contains(StatxFlags::BTIME);
+        Self {
+            mode: stx.stx_mode.into(),
+            nlink: stx.stx_nlink.into(),
+            uid: stx.stx_uid,
+            gid: stx.stx_gid,
+            ino: stx.stx_ino,
+            size: stx.stx_size,
+            blocks: stx.stx_blocks,
+            blksize: stx.stx_blksize.into(),
+            dev: makedev(stx.stx_dev_major, stx.stx_dev_minor),
+            rdev: makedev(stx.stx_rdev_major, stx.stx_rdev_minor),
+            accessed: to_system_time(stx.stx_atime),
+            modified: to_system_time(stx.stx_mtime),
+            changed: to_system_time(stx.stx_ctime),
+            born: has_birth.then(|| to_system_time(stx.stx_btime)).flatten(),
+        }
+    }
+
+    fn time(&self, field: MetadataTimeField) -> Option<SystemTime> {
+        match field {
+            MetadataTimeField::Access => self.accessed,
+            MetadataTimeField::Modification => self.modified,
+            MetadataTimeField::Change => self.changed,
+            MetadataTimeField::Birth => self.born,
+        }
+    }
+
+    fn file_type(&self) -> mode_t {
+        self.mode as mode_t & S_IFMT
+    }
+}
+
 #[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
 struct Flags {
     alter: bool,
@@ -310,6 +433,7 @@ fn group_num(s: &str) -> Cow<'_, str> {
 
 struct Stater {
     follow: bool,
+    cached: CachedMode,
     show_fs: bool,
     from_user: bool,
     files: Vec<OsString>,
@@ -478,7 +602,7 @@ fn warn_invalid_quoting_style(style: &str) {
 fn get_quoted_file_name(
     display_name: &str,
     file: &OsString,
-    file_type: FileType,
+    is_symlink: bool,
     from_user: bool,
 ) -> Result<String, i32> {
     let quoting_style = match env::var("QUOTING_STYLE") {
@@ -491,7 +615,7 @@ fn get_quoted_file_name(
         Err(_) => QuotingStyle::default(),
     };
 
-    if file_type.is_symlink() {
+    if is_symlink {
         let quoted_display_name = quote_file_name(display_name, &quoting_style);
         match fs::read_link(file) {
             Ok(dst) => {
@@ -1002,6 +1126,9 @@ impl Stater {
 
         Ok(Self {
             follow: matches.get_flag(options::DEREFERENCE),
+            cached: matches
+                .get_one::<String>(options::CACHED)
+                .map_or(CachedMode::Default, |s| CachedMode::from_arg(s)),
             show_fs,
             from_user: !format_str.is_empty(),
             files,
@@ -1057,10 +1184,9 @@ impl Stater {
     fn process_token_files(
         &self,
         t: &Token,
-        meta: &Metadata,
+        meta: &FileAttributes,
         display_name: &str,
         file: &OsString,
-        file_type: FileType,
         from_user: bool,
         #[cfg(all(feature = "selinux", any(target_os = "linux", target_os = "android")))]
         follow_symbolic_links: bool,
@@ -1079,11 +1205,11 @@ impl Stater {
             } => {
                 let output = match format {
                     // access rights in octal
-                    'a' => OutputType::UnsignedOct(0o7777 & meta.mode()),
+                    'a' => OutputType::UnsignedOct(0o7777 & meta.mode),
                     // access rights in human readable form
-                    'A' => OutputType::Str(display_permissions(meta, true)),
+                    'A' => OutputType::Str(display_permissions_unix(meta.mode, true)),
                     // number of blocks allocated (see %B)
-                    'b' => OutputType::Unsigned(meta.blocks()),
+                    'b' => OutputType::Unsigned(meta.blocks),
 
                     // the size in bytes of each block reported by %b
                     // FIXME: blocksize differs on various platform
@@ -1119,27 +1245,27 @@ impl Stater {
                         }
                     }
                     // device number in decimal
-                    'd' if flag.major => OutputType::Unsigned(major(meta.dev() as _) as u64),
-                    'd' if flag.minor => OutputType::Unsigned(minor(meta.dev() as _) as u64),
-                    'd' => OutputType::Unsigned(meta.dev()),
+                    'd' if flag.major => OutputType::Unsigned(major(meta.dev as _) as u64),
+                    'd' if flag.minor => OutputType::Unsigned(minor(meta.dev as _) as u64),
+                    'd' => OutputType::Unsigned(meta.dev),
                     // device number in hex
-                    'D' => OutputType::UnsignedHex(meta.dev()),
+                    'D' => OutputType::UnsignedHex(meta.dev),
                     // raw mode in hex
-                    'f' => OutputType::UnsignedHex(meta.mode() as u64),
+                    'f' => OutputType::UnsignedHex(meta.mode as u64),
                     // file type
-                    'F' => OutputType::Str(pretty_filetype(meta.mode() as mode_t, meta.len())),
+                    'F' => OutputType::Str(pretty_filetype(meta.mode as mode_t, meta.size)),
                     // group ID of owner
-                    'g' => OutputType::Unsigned(meta.gid() as u64),
+                    'g' => OutputType::Unsigned(meta.gid as u64),
                     // group name of owner
                     'G' => {
                         let group_name =
-                            entries::gid2grp(meta.gid()).unwrap_or_else(|_| "UNKNOWN".to_owned());
+                            entries::gid2grp(meta.gid).unwrap_or_else(|_| "UNKNOWN".to_owned());
                         OutputType::Str(group_name)
                     }
                     // number of hard links
-                    'h' => OutputType::Unsigned(meta.nlink()),
+                    'h' => OutputType::Unsigned(meta.nlink),
                     // inode number
-                    'i' => OutputType::Unsigned(meta.ino()),
+                    'i' => OutputType::Unsigned(meta.ino),
                     // mount point
                     'm' => match self.find_mount_point(file) {
                         Some(s) => OutputType::OsStr(s),
@@ -1149,66 +1275,73 @@ impl Stater {
                     'n' => OutputType::Str(display_name.to_string()),
                     // quoted file name with dereference if symbolic link
                     'N' => {
-                        let file_name =
-                            get_quoted_file_name(display_name, file, file_type, from_user)?;
+                        let file_name = get_quoted_file_name(
+                            display_name,
+                            file,
+                            meta.file_type() == S_IFLNK,
+                            from_user,
+                        )?;
                         OutputType::Str(file_name)
                     }
                     // optimal I/O transfer size hint
-                    'o' => OutputType::Unsigned(meta.blksize()),
+                    'o' => OutputType::Unsigned(meta.blksize),
                     // total size, in bytes
-                    's' => OutputType::Integer(meta.len() as i64),
+                    's' => OutputType::Integer(meta.size as i64),
                     // major device type in hex, for character/block device special
                     // files
-                    't' => OutputType::UnsignedHex(major(meta.rdev() as _) as u64),
+                    't' => OutputType::UnsignedHex(major(meta.rdev as _) as u64),
                     // minor device type in hex, for character/block device special
                     // files
-                    'T' => OutputType::UnsignedHex(minor(meta.rdev() as _) as u64),
+                    'T' => OutputType::UnsignedHex(minor(meta.rdev as _) as u64),
                     // user ID of owner
-                    'u' => OutputType::Unsigned(meta.uid() as u64),
+                    'u' => OutputType::Unsigned(meta.uid as u64),
                     // user name of owner
                     'U' => {
                         let user_name =
-                            entries::uid2usr(meta.uid()).unwrap_or_else(|_| "UNKNOWN".to_owned());
+                            entries::uid2usr(meta.uid).unwrap_or_else(|_| "UNKNOWN".to_owned());
                         OutputType::Str(user_name)
                     }
 
                     // time of file birth, human-readable; - if unknown
-                    'w' => OutputType::Str(pretty_time(meta, MetadataTimeField::Birth)),
+                    'w' => OutputType::Str(pretty_time(meta.time(MetadataTimeField::Birth))),
 
                     // time of file birth, seconds since Epoch; 0 if unknown
                     'W' => OutputType::Integer(
-                        metadata_get_time(meta, MetadataTimeField::Birth)
+                        meta.time(MetadataTimeField::Birth)
                             .map_or(0, |x| system_time_to_sec(x).0),
                     ),
 
                     // time of last access, human-readable
-                    'x' => OutputType::Str(pretty_time(meta, MetadataTimeField::Access)),
+                    'x' => OutputType::Str(pretty_time(meta.time(MetadataTimeField::Access))),
                     // time of last access, seconds since Epoch
                     'X' => {
-                        let (sec, nsec) = metadata_get_time(meta, MetadataTimeField::Access)
+                        let (sec, nsec) = meta
+                            .time(MetadataTimeField::Access)
                             .map_or((0, 0), system_time_to_sec);
                         OutputType::Float(sec as f64 + nsec as f64 / 1_000_000_000.0)
                     }
                     // time of last data modification, human-readable
-                    'y' => OutputType::Str(pretty_time(meta, MetadataTimeField::Modification)),
+                    'y' => OutputType::Str(pretty_time(meta.time(MetadataTimeField::Modification))),
                     // time of last data modification, seconds since Epoch
                     'Y' => {
-                        let (sec, nsec) = metadata_get_time(meta, MetadataTimeField::Modification)
+                        let (sec, nsec) = meta
+                            .time(MetadataTimeField::Modification)
                             .map_or((0, 0), system_time_to_sec);
                         OutputType::Float(sec as f64 + nsec as f64 / 1_000_000_000.0)
                     }
                     // time of last status change, human-readable
-                    'z' => OutputType::Str(pretty_time(meta, MetadataTimeField::Change)),
+                    'z' => OutputType::Str(pretty_time(meta.time(MetadataTimeField::Change))),
                     // time of last status change, seconds since Epoch
                     'Z' => {
-                        let (sec, nsec) = metadata_get_time(meta, MetadataTimeField::Change)
+                        let (sec, nsec) = meta
+                            .time(MetadataTimeField::Change)
                             .map_or((0, 0), system_time_to_sec);
                         OutputType::Float(sec as f64 + nsec as f64 / 1_000_000_000.0)
                     }
-                    'R' => OutputType::UnsignedHex(meta.rdev()),
-                    'r' if flag.major => OutputType::Unsigned(major(meta.rdev() as _) as u64),
-                    'r' if flag.minor => OutputType::Unsigned(minor(meta.rdev() as _) as u64),
-                    'r' => OutputType::Unsigned(meta.rdev()),
+                    'R' => OutputType::UnsignedHex(meta.rdev),
+                    'r' if flag.major => OutputType::Unsigned(major(meta.rdev as _) as u64),
+                    'r' if flag.minor => OutputType::Unsigned(minor(meta.rdev as _) as u64),
+                    'r' => OutputType::Unsigned(meta.rdev),
                     _ => OutputType::Unknown,
                 };
                 print_it(&output, flag, width, precision);
@@ -1217,6 +1350,37 @@ impl Stater {
         Ok(())
     }
 
+    /// Reads `file`'s attributes with a single `statx` call that carries the
+    /// `--cached` synchronization flag. Where `statx` is unavailable, the
+    /// flag is ignored and [`fs::metadata`] is used instead.
+    #[cfg(target_os = "linux")]
+    fn read_attributes(&self, file: &OsStr, follow: bool) -> std::io::Result<FileAttributes> {
+        use rustix::fs::{AtFlags, CWD, StatxFlags, statx};
+        use rustix::io::Errno;
+
+        let mut flags = self.cached.statx_sync_flags();
+        if !follow {
+            flags |= AtFlags::SYMLINK_NOFOLLOW;
+        }
+        match statx(
+            CWD,
+            file,
+            flags,
+            StatxFlags::BASIC_STATS | StatxFlags::BTIME,
+        ) {
+            Ok(stx) => Ok(FileAttributes::from_statx(&stx)),
+            // Old kernels, or sandboxes that filter the system call.
+            Err(Errno::NOSYS | Errno::PERM) => read_metadata(file, follow),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// `--cached` is accepted but has no effect without `statx`.
+    #[cfg(not(target_os = "linux"))]
+    fn read_attributes(&self, file: &OsStr, follow: bool) -> std::io::Result<FileAttributes> {
+        read_metadata(file, follow)
+    }
+
     fn do_stat(&self, file: &OsStr, stdin_is_fifo: bool) -> i32 {
         let display_name = file.to_string_lossy();
         let file = if cfg!(unix) && display_name == "-" {
@@ -1255,21 +1419,15 @@ impl Stater {
             }
         } else {
             let follow_symbolic_links = self.follow || stdin_is_fifo && display_name == "-";
-            let result = if follow_symbolic_links {
-                fs::metadata(&file)
-            } else {
-                fs::symlink_metadata(&file)
-            };
-            match result {
+            match self.read_attributes(&file, follow_symbolic_links) {
                 Ok(meta) => {
                     let file_type = meta.file_type();
-                    let tokens = if self.from_user
-                        || !(file_type.is_char_device() || file_type.is_block_device())
-                    {
-                        &self.default_tokens
-                    } else {
-                        &self.default_dev_tokens
-                    };
+                    let tokens =
+                        if self.from_user || !(file_type == S_IFCHR || file_type == S_IFBLK) {
+                            &self.default_tokens
+                        } else {
+                            &self.default_dev_tokens
+                        };
 
                     for t in tokens {
                         if let Err(code) = self.process_token_files(
@@ -1277,7 +1435,6 @@ impl Stater {
                             &meta,
                             &display_name,
                             &file,
-                            file_type,
                             self.from_user,
                             follow_symbolic_links,
                         ) {
@@ -1396,6 +1553,13 @@ pub fn uu_app() -> Command {
                 .help(translate!("stat-help-dereference"))
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new(options::CACHED)
+                .long(options::CACHED)
+                .value_name("MODE")
+                .help(translate!("stat-help-cached"))
+                .value_parser(ShortcutValueParser::new(["default", "never", "always"])),
+        )
         .arg(
             Arg::new(options::FILE_SYSTEM)
                 .short('f')
@@ -1431,10 +1595,19 @@ pub fn uu_app() -> Command {
         )
 }
 
+fn read_metadata(file: &OsStr, follow: bool) -> std::io::Result<FileAttributes> {
+    let meta = if follow {
+        fs::metadata(file)
+    } else {
+        fs::symlink_metadata(file)
+    };
+    meta.map(|meta| FileAttributes::from_metadata(&meta))
+}
+
 const PRETTY_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S.%N %z";
 
-fn pretty_time(meta: &Metadata, md_time_field: MetadataTimeField) -> String {
-    if let Some(time) = metadata_get_time(meta, md_time_field) {
+fn pretty_time(time: Option<SystemTime>) -> String {
+    if let Some(time) = time {
         let mut tmp = Vec::new();
         if format_system_time(
             &mut tmp,
@@ -1454,7 +1627,9 @@ fn pretty_time(meta: &Metadata, md_time_field: MetadataTimeField) -> String {
 mod tests {
     use crate::{quote_file_name, write_padded_bytes, write_padding};
 
-    use super::{Flags, Precision, ScanUtil, Stater, Token, group_num, precision_trunc};
+    use super::{
+        CachedMode, Flags, Precision, ScanUtil, Stater, Token, group_num, precision_trunc,
+    };
 
     #[test]
     fn test_scanners() {
@@ -1618,4 +1793,37 @@ mod tests {
             "\'nice\" file\'"
         );
     }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn test_cached_mode_statx_flags() {
+        use uucore::libc::{AT_STATX_DONT_SYNC, AT_STATX_FORCE_SYNC, AT_STATX_SYNC_AS_STAT};
+
+        assert_eq!(CachedMode::from_arg("default"), CachedMode::Default);
+        assert_eq!(CachedMode::from_arg("always"), CachedMode::Always);
+        assert_eq!(CachedMode::from_arg("never"), CachedMode::Never);
+        let bits = |mode: CachedMode| mode.statx_sync_flags().bits() as i32;
+        assert_eq!(bits(CachedMode::Default), AT_STATX_SYNC_AS_STAT);
+        assert_eq!(bits(CachedMode::Always), AT_STATX_DONT_SYNC);
+        assert_eq!(bits(CachedMode::Never), AT_STATX_FORCE_SYNC);
+    }
+
+    #[test]
+    fn test_cached_mode_read_attributes() {
+        use std::os::unix::fs::MetadataExt;
+
+        let expected = std::fs::metadata("Cargo.toml").unwrap();
+        for mode in ["always", "default", "never"] {
+            let matches = crate::uu_app()
+                .try_get_matches_from(["stat", "--cached", mode, "Cargo.toml"])
+                .unwrap();
+            let stater = Stater::new(&matches).unwrap();
+            let attrs = stater
+                .read_attributes("Cargo.toml".as_ref(), false)
+                .unwrap();
+            assert_eq!(attrs.ino, expected.ino());
+            assert_eq!(attrs.size, expected.len());
+            assert_eq!(attrs.mode, expected.mode());
+        }
+    }
 }
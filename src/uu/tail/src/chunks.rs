@@ -46,13 +46,16 @@ pub struct ReverseChunks<'a> {
 }
 
 impl<'a> ReverseChunks<'a> {
-    pub fn new(file: &'a mut File) -> Self {
+    /// Iterate backwards from offset `end`, which is normally the size of
+    /// the file when the caller first looked at it.
+    pub fn new(file: &'a mut File, end: u64) -> Self {
         let current = if cfg!(unix) {
             file.stream_position().unwrap()
         } else {
             0
         };
-        let size = file.seek(SeekFrom::End(0)).unwrap() - current;
+        let size = end.saturating_sub(current);
+        file.seek(SeekFrom::Start(current + size)).unwrap();
         let max_blocks_to_read = (size as f64 / BLOCK_SIZE as f64).ceil() as usize;
         let block_idx = 0;
         ReverseChunks {
@@ -490,6 +490,43 @@ impl Observer {
         }
         Ok(paths)
     }
+
+    /// Reopen every followed path whose name now refers to a different file
+    /// than the one we hold open, e.g. after a log rotation that the polling
+    /// backend failed to report.
+    fn reopen_replaced_files(&mut self, settings: &Settings) -> UResult<()> {
+        let paths = self.files.keys().cloned().collect::<Vec<_>>();
+        for path in &paths {
+            let Ok(new_md) = path.metadata() else {
+                continue;
+            };
+            // Like in `handle_event`, a name swapped for a symlink is not
+            // followed to its target.
+            if path
+                .symlink_metadata()
+                .is_ok_and(|m| m.file_type().is_symlink())
+            {
+                continue;
+            }
+            let pd = self.files.get(path);
+            let replaced = pd.reader.is_some()
+                && new_md.is_tailable()
+                && pd
+                    .metadata
+                    .as_ref()
+                    .is_some_and(|old_md| !old_md.file_id_eq(&new_md));
+            if replaced {
+                show_error!(
+                    "{}",
+                    translate!("tail-status-has-been-replaced-following-new-file", "file" => pd.display_name.quote())
+                );
+                self.files.update_reader(path)?;
+                self.files.update_metadata(path, Some(new_md));
+                self.files.tail_file(path, settings.verbose)?;
+            }
+        }
+        Ok(())
+    }
 }
 
 #[allow(clippy::cognitive_complexity)]
@@ -654,7 +691,7 @@ pub fn follow(mut observer: Observer, settings: &Settings) -> UResult<()> {
             _read_some = observer.files.tail_file(path, settings.verbose)?;
         }
 
-        if timeout_counter == settings.max_unchanged_stats {
+        if timeout_counter >= settings.max_unchanged_stats {
             /*
             TODO: [2021-10; jhscheer] implement timeout_counter for each file.
             '--max-unchanged-stats=n'
@@ -666,6 +703,10 @@ pub fn follow(mut observer: Observer, settings: &Settings) -> UResult<()> {
             have accumulated in the new log file. This option is meaningful only when polling
             (i.e., without inotify) and when following by name.
             */
+            timeout_counter = 0;
+            if observer.use_polling && observer.follow_name() {
+                observer.reopen_replaced_files(settings)?;
+            }
         }
     }
 
@@ -406,19 +406,19 @@ fn forwards_thru_file(
     }
 }
 
-/// Iterate over bytes in the file, in reverse, until we find the
-/// `num_delimiters` instance of `delimiter`. The `file` is left seek'd to the
-/// position just after that delimiter.
-fn backwards_thru_file(file: &mut File, num_delimiters: u64, delimiter: u8) {
+/// Iterate over bytes in the file, in reverse from offset `end`, until we
+/// find the `num_delimiters` instance of `delimiter`. The `file` is left
+/// seek'd to the position just after that delimiter.
+fn backwards_thru_file(file: &mut File, end: u64, num_delimiters: u64, delimiter: u8) {
     if num_delimiters == 0 {
-        file.seek(SeekFrom::End(0)).unwrap();
+        file.seek(SeekFrom::Start(end)).unwrap();
         return;
     }
     // This variable counts the number of delimiters found in the file
     // so far (reading from the end of the file toward the beginning).
     let mut counter = 0;
     let mut first_slice = true;
-    for slice in ReverseChunks::new(file) {
+    for slice in ReverseChunks::new(file, end) {
         // Iterate over each byte in the slice in reverse order.
         let mut iter = memrchr_iter(delimiter, &slice);
 
@@ -462,7 +462,14 @@ fn bounded_tail(file: &mut File, settings: &Settings) -> UResult<()> {
     // Find the position in the file to start printing from.
     match &settings.mode {
         FilterMode::Lines(Signum::Negative(count), delimiter) => {
-            backwards_thru_file(file, *count, *delimiter);
+            // Only print up to the end of the file as it is right now, so
+            // that data appended while we are reading is left for `-f`.
+            let start = file.stream_position()?;
+            let end = file.seek(SeekFrom::End(0))?;
+            file.seek(SeekFrom::Start(start))?;
+            backwards_thru_file(file, end, *count, *delimiter);
+            // If the file shrank meanwhile, the copy simply stops at EOF.
+            limit = Some(end.saturating_sub(file.stream_position()?));
         }
         FilterMode::Lines(Signum::Positive(count), delimiter) if count > &1 => {
             let i = forwards_thru_file(file, *count - 1, *delimiter).unwrap();
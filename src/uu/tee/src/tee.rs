@@ -124,7 +124,7 @@ fn open(
             name: name.clone(),
         })),
         Err(f) => {
-            let _ = writeln!(stderr(), "{}: {f}", name.maybe_quote());
+            let _ = writeln!(stderr(), "tee: {}: {}", name.maybe_quote(), strip_errno(&f));
             match output_error {
                 Some(OutputErrorMode::Exit | OutputErrorMode::ExitNoPipe) => Some(Err(f)),
                 _ => None,
@@ -270,7 +270,12 @@ fn process_error(
     if ignore_pipe && e.kind() == ErrorKind::BrokenPipe {
         return Ok(());
     }
-    let _ = writeln!(stderr(), "{}: {e}", writer.name.maybe_quote());
+    let _ = writeln!(
+        stderr(),
+        "tee: {}: {}",
+        writer.name.maybe_quote(),
+        strip_errno(&e)
+    );
     if let Some(OutputErrorMode::Exit | OutputErrorMode::ExitNoPipe) = mode {
         Err(())
     } else {
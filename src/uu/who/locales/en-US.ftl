@@ -32,7 +32,7 @@ who-user-count = # { $count ->
 }
 
 # Idle time indicators
-who-idle-old =  old
+who-idle-old = old
 
 # System information
 who-runlevel = run-level { $level }
@@ -156,20 +156,26 @@ fn idle_string<'a>(when: i64, boottime: i64) -> Cow<'a, str> {
                 .into()
             }
         } else {
-            translate!("who-idle-old").into()
+            // Centered in the 5-column idle field, like "  .  " above.
+            format!(" {} ", translate!("who-idle-old")).into()
         }
     })
 }
 
-fn time_string(ut: &UtmpxRecord) -> String {
-    const FORMAT_DESCRIPTION_VERSION: usize = 2;
-
-    let time_format: Vec<time::format_description::FormatItem> = if ["LC_ALL", "LC_TIME", "LANG"]
+/// Whether login times use the short `%b %e %H:%M` format of the C locale
+/// rather than `%Y-%m-%d %H:%M`.
+fn uses_c_time_format() -> bool {
+    ["LC_ALL", "LC_TIME", "LANG"]
         .into_iter()
         .find_map(std::env::var_os)
         .as_deref()
         == Some(std::ffi::OsStr::new("C"))
-    {
+}
+
+fn time_string(ut: &UtmpxRecord) -> String {
+    const FORMAT_DESCRIPTION_VERSION: usize = 2;
+
+    let time_format: Vec<time::format_description::FormatItem> = if uses_c_time_format() {
         // "%b %e %H:%M"
         time::format_description::parse_borrowed::<FORMAT_DESCRIPTION_VERSION>(
             "[month repr:short] [day padding:space] [hour]:[minute]",
@@ -436,8 +442,8 @@ impl Who {
             buf.push_str(&msg);
         }
         write!(buf, " {line:<12}").unwrap();
-        // "%b %e %H:%M" (LC_ALL=C)
-        let time_size = 3 + 2 + 2 + 1 + 2;
+        // "%b %e %H:%M" or "%Y-%m-%d %H:%M"
+        let time_size = if uses_c_time_format() { 12 } else { 16 };
         write!(buf, " {time:<time_size$}").unwrap();
 
         if !self.short_output {
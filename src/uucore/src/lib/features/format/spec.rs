@@ -339,12 +339,14 @@ impl Spec {
         length
     }
 
+    /// Writes the formatted argument, returning [`ControlFlow::Break`] when a
+    /// `\c` in a `%b` argument asks to stop producing output.
     pub fn write(
         &self,
         mut writer: impl Write,
         args: &mut FormatArguments,
-    ) -> Result<(), FormatError> {
-        match self {
+    ) -> Result<ControlFlow<()>, FormatError> {
+        let result = match self {
             Self::Char {
                 width,
                 align_left,
@@ -387,16 +389,15 @@ impl Spec {
                 let bytes = os_str_as_bytes(os_str)?;
                 let mut parsed = Vec::<u8>::new();
 
+                let mut flow = ControlFlow::Continue(());
                 for c in parse_escape_only(bytes, OctalParsing::ThreeDigits) {
-                    match c.write(&mut parsed)? {
-                        ControlFlow::Continue(()) => {}
-                        ControlFlow::Break(()) => {
-                            // TODO: This should break the _entire execution_ of printf
-                            break;
-                        }
+                    flow = c.write(&mut parsed)?;
+                    if flow.is_break() {
+                        break;
                     }
                 }
-                writer.write_all(&parsed).map_err(FormatError::IoError)
+                writer.write_all(&parsed).map_err(FormatError::IoError)?;
+                return Ok(flow);
             }
             Self::QuotedString { position } => {
                 let s = locale_aware_escape_name(
@@ -496,7 +497,8 @@ impl Spec {
                 .fmt(writer, &f)
                 .map_err(FormatError::IoError)
             }
-        }
+        };
+        result.map(|()| ControlFlow::Continue(()))
     }
 }
 
@@ -85,6 +85,27 @@ fn test_no_options_big_input() {
     }
 }
 
+#[test]
+fn test_no_options_binary_passthrough() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    // Every byte value, followed by invalid UTF-8 sequences and NULs.
+    let mut data: Vec<u8> = (0..=255).collect();
+    data.extend_from_slice(b"\xff\xfe\0\0\xc3(\xe2\x82\n\0");
+    at.write_bytes("binary", &data);
+    at.write_bytes("lone", b"\x80");
+
+    let mut expected = data.clone();
+    expected.push(0x80);
+    ucmd.args(&["binary", "lone"])
+        .succeeds()
+        .stdout_only_bytes(&expected);
+
+    new_ucmd!()
+        .pipe_in(data.clone())
+        .succeeds()
+        .stdout_only_bytes(&data);
+}
+
 #[test]
 #[cfg(unix)]
 fn test_fifo_symlink() {
@@ -399,6 +420,17 @@ fn test_show_ends_crlf() {
         .stdout_only("a$\nb^M$\n\rc$\n^M$\n\r");
 }
 
+#[test]
+fn test_show_all_crlf() {
+    for arg in ["-A", "-vE"] {
+        new_ucmd!()
+            .arg(arg)
+            .pipe_in("a\nb\r\n\rc\n\r\n\r")
+            .succeeds()
+            .stdout_only("a$\nb^M$\n^Mc$\n^M$\n^M");
+    }
+}
+
 #[test]
 fn test_stdin_show_all() {
     for same_param in ["-A", "--show-all", "--show-a"] {
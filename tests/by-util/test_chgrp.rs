@@ -654,6 +654,67 @@ fn test_chgrp_recursive_on_file() {
     );
 }
 
+#[test]
+fn test_chgrp_recursive_verbose_lists_every_entry() {
+    let current_gid = getegid();
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.mkdir_all("tree/sub");
+    at.touch("tree/a");
+    at.touch("tree/sub/b");
+
+    let result = ucmd
+        .arg("-Rv")
+        .arg(current_gid.to_string())
+        .arg("tree")
+        .succeeds();
+    result.no_stderr();
+    for path in ["tree", "tree/a", "tree/sub", "tree/sub/b"] {
+        result.stdout_contains(format!("group of '{path}' retained as "));
+    }
+    assert_eq!(result.stdout_str().lines().count(), 4);
+}
+
+#[test]
+#[cfg(not(target_vendor = "apple"))]
+fn test_reference_sets_group_of_target() {
+    use std::os::unix::fs::MetadataExt;
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.touch("ref_file");
+    at.touch("target_file");
+
+    let target_gid = at.plus("target_file").metadata().unwrap().gid();
+    // Root may use any group; others need a second group they belong to.
+    let other_gid = if uucore::process::geteuid() == 0 {
+        Some(target_gid.wrapping_add(1))
+    } else {
+        getgroups()
+            .unwrap()
+            .into_iter()
+            .map(rustix::process::Gid::as_raw)
+            .find(|&g| g != target_gid)
+    };
+    let Some(other_gid) = other_gid else {
+        return;
+    };
+
+    scene
+        .ucmd()
+        .arg(other_gid.to_string())
+        .arg("ref_file")
+        .succeeds();
+    scene
+        .ucmd()
+        .arg("-c")
+        .arg("--reference=ref_file")
+        .arg("target_file")
+        .succeeds()
+        .stdout_contains("changed group of 'target_file' from ")
+        .no_stderr();
+
+    assert_eq!(at.plus("target_file").metadata().unwrap().gid(), other_gid);
+}
+
 #[test]
 fn test_chgrp_exit_code_not_being_overwritten_by_last_file() {
     use std::os::unix::prelude::PermissionsExt;
@@ -1921,6 +1921,53 @@ fn test_check_failed_to_read() {
         .fails()
         .stdout_is("dir: FAILED open or read\n")
         .stderr_contains("cksum: WARNING: 1 listed file could not be read");
+
+    // `--status` hides the per-file report, but not the read error itself
+    scene
+        .ucmd()
+        .arg("--check")
+        .arg("--status")
+        .arg("CHECKSUM")
+        .fails()
+        .no_stdout()
+        .stderr_contains("cksum: dir: Is a directory")
+        .stderr_does_not_contain("FAILED");
+}
+
+#[test]
+fn test_check_generated_checksums() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.write("good", "good\n");
+    at.write("bad", "bad\n");
+    at.write("gone", "gone\n");
+
+    for untagged in [false, true] {
+        let mut cmd = scene.ucmd();
+        cmd.args(&["-a", "sha256", "good", "bad", "gone"]);
+        if untagged {
+            cmd.arg("--untagged");
+        }
+        let sums = cmd.succeeds().stdout_move_str();
+        at.write("SUMS", &sums);
+        at.write("bad", "changed\n");
+        at.remove("gone");
+
+        scene
+            .ucmd()
+            .args(&["-a", "sha256", "--ignore-missing", "-c", "SUMS"])
+            .fails_with_code(1)
+            .stdout_is("good: OK\nbad: FAILED\n")
+            .stderr_is("cksum: WARNING: 1 computed checksum did NOT match\n");
+        scene
+            .ucmd()
+            .args(&["-a", "sha256", "--quiet", "-c", "SUMS"])
+            .fails_with_code(1)
+            .stdout_is("bad: FAILED\ngone: FAILED open or read\n");
+
+        at.write("bad", "bad\n");
+        at.write("gone", "gone\n");
+    }
 }
 
 #[test]
@@ -309,7 +309,6 @@ fn zero_terminated_with_total() {
     }
 }
 
-#[ignore = "not implemented"]
 #[test]
 fn check_order() {
     let scene = TestScenario::new(util_name!());
@@ -319,19 +318,19 @@ fn check_order() {
     scene
         .ucmd()
         .args(&["--check-order", "bad_order_1", "bad_order_2"])
-        .fails()
-        .stdout_is("\t\te")
-        .stderr_is("error to be defined");
+        .fails_with_code(1)
+        .stdout_is("\t\te\n")
+        .stderr_is("comm: file 1 is not in sorted order\n");
 }
 
-#[ignore = "not implemented"]
 #[test]
 fn nocheck_order() {
     let scene = TestScenario::new(util_name!());
     let at = &scene.fixtures;
     at.write("bad_order_1", "e\nd\nb\na\n");
     at.write("bad_order_2", "e\nc\nb\na\n");
-    new_ucmd!()
+    scene
+        .ucmd()
         .args(&["--nocheck-order", "bad_order_1", "bad_order_2"])
         .succeeds()
         .stdout_is("\t\te\n\tc\n\tb\n\ta\nd\nb\na\n");
@@ -312,6 +312,40 @@ fn test_cp_multiple_files_with_nonexistent_file() {
     assert_eq!(at.read(TEST_HOW_ARE_YOU_DEST), "How are you?\n");
 }
 
+#[test]
+#[cfg(unix)]
+fn test_cp_multiple_files_with_unreadable_file() {
+    // Root can read a mode-000 file, so the copy would not fail.
+    if uucore::process::geteuid() == 0 {
+        return;
+    }
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("unreadable", "secret\n");
+    at.set_mode("unreadable", 0o000);
+    ucmd.arg(TEST_HELLO_WORLD_SOURCE)
+        .arg("unreadable")
+        .arg(TEST_HOW_ARE_YOU_SOURCE)
+        .arg(TEST_COPY_TO_FOLDER)
+        .fails_with_code(1)
+        .stderr_contains("'unreadable': Permission denied");
+
+    assert_eq!(at.read(TEST_COPY_TO_FOLDER_FILE), "Hello, World!\n");
+    assert_eq!(at.read(TEST_HOW_ARE_YOU_DEST), "How are you?\n");
+    assert!(!at.file_exists("hello_dir/unreadable"));
+}
+
+#[test]
+fn test_cp_multiple_files_with_repeated_nonexistent_file() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    ucmd.arg(TEST_NONEXISTENT_FILE)
+        .arg(TEST_NONEXISTENT_FILE)
+        .arg(TEST_HOW_ARE_YOU_SOURCE)
+        .arg(TEST_COPY_TO_FOLDER)
+        .fails_with_code(1);
+
+    assert_eq!(at.read(TEST_HOW_ARE_YOU_DEST), "How are you?\n");
+}
+
 #[test]
 fn test_cp_multiple_files_with_empty_file_name() {
     #[cfg(windows)]
@@ -939,6 +973,124 @@ fn test_cp_arg_no_clobber() {
     assert_eq!(at.read(TEST_HOW_ARE_YOU_SOURCE), "How are you?\n");
 }
 
+#[test]
+fn test_cp_no_clobber_skips_silently_with_interactive_and_force() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.write("src", "new\n");
+    at.write("dest", "old\n");
+
+    for args in [&["-n"][..], &["-n", "-f"], &["-f", "-n"], &["-i", "-n"]] {
+        scene
+            .ucmd()
+            .args(args)
+            .args(&["src", "dest"])
+            .succeeds()
+            .no_output();
+        assert_eq!(at.read("dest"), "old\n");
+    }
+
+    // -i given after -n wins and prompts.
+    scene
+        .ucmd()
+        .args(&["-n", "-i", "src", "dest"])
+        .pipe_in("n\n")
+        .fails()
+        .stderr_contains("overwrite 'dest'?");
+    assert_eq!(at.read("dest"), "old\n");
+}
+
+#[test]
+fn test_cp_fsync() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.mkdir_all("dir/sub");
+    at.write("dir/sub/file", "content");
+
+    ucmd.args(&["--fsync", "-r", "dir", "copy"])
+        .succeeds()
+        .no_output();
+    assert_eq!(at.read("copy/sub/file"), "content");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_cp_fsync_skips_special_destination() {
+    // fsync(2) fails with EINVAL on /dev/null, so it must not be attempted.
+    new_ucmd!()
+        .args(&["--fsync", TEST_HELLO_WORLD_SOURCE, "/dev/null"])
+        .succeeds()
+        .no_output();
+}
+
+#[test]
+#[cfg(unix)]
+fn test_cp_fsync_fifo_destination() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("src", "hello");
+    at.mkfifo("fifo");
+    let fifo = at.plus("fifo");
+    let reader = std::thread::spawn(move || std::fs::read(fifo).unwrap());
+
+    // Reopening the FIFO to sync it would block with no writer left.
+    ucmd.args(&["--fsync", "src", "fifo"])
+        .timeout(Duration::from_secs(10))
+        .succeeds()
+        .no_output();
+    assert_eq!(reader.join().unwrap(), b"hello");
+}
+
+#[test]
+#[cfg(unix)]
+fn test_cp_atomic_replaces_inode() {
+    use std::io::Read;
+
+    let (at, mut ucmd) = at_and_ucmd!();
+    let content = "line\n".repeat(100_000);
+    at.write("src", &content);
+    at.write("dest", "old\n");
+    at.set_mode("src", 0o640);
+    let old_ino = at.metadata("dest").ino();
+    // A reader that opened the destination before the copy keeps the old
+    // file; it is never truncated or partially rewritten.
+    let mut reader = std::fs::File::open(at.plus("dest")).unwrap();
+
+    ucmd.args(&["--atomic", "src", "dest"])
+        .succeeds()
+        .no_output();
+
+    let mut seen = String::new();
+    reader.read_to_string(&mut seen).unwrap();
+    assert_eq!(seen, "old\n");
+    assert_ne!(at.metadata("dest").ino(), old_ino);
+    assert_eq!(at.read("dest"), content);
+    // An existing destination keeps its mode, as with a regular copy.
+    assert_eq!(at.metadata("dest").mode() & 0o777, 0o644);
+    // No temporary file is left behind.
+    assert!(
+        std::fs::read_dir(at.as_string()).unwrap().all(|e| !e
+            .unwrap()
+            .file_name()
+            .as_encoded_bytes()
+            .starts_with(b"."))
+    );
+}
+
+#[test]
+#[cfg(unix)]
+fn test_cp_atomic_through_symlink() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("src", "new\n");
+    at.write("target", "old\n");
+    at.relative_symlink_file("target", "link");
+
+    ucmd.args(&["--atomic", "src", "link"])
+        .succeeds()
+        .no_output();
+
+    assert!(at.is_symlink("link"));
+    assert_eq!(at.read("target"), "new\n");
+}
+
 #[test]
 fn test_cp_arg_no_clobber_inferred_arg() {
     let (at, mut ucmd) = at_and_ucmd!();
@@ -1579,6 +1731,24 @@ fn test_cp_parents_multiple_files() {
     );
 }
 
+#[test]
+fn test_cp_parents_shared_prefix() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.mkdir_all("a/b");
+    at.mkdir("dest");
+    at.write("a/b/c.txt", "c\n");
+    at.write("a/b/d.txt", "d\n");
+
+    ucmd.args(&["--parents", "a/b/c.txt", "a/b/d.txt", "dest"])
+        .succeeds()
+        .no_output();
+
+    assert!(at.dir_exists("dest/a"));
+    assert!(at.dir_exists("dest/a/b"));
+    assert_eq!(at.read("dest/a/b/c.txt"), "c\n");
+    assert_eq!(at.read("dest/a/b/d.txt"), "d\n");
+}
+
 #[test]
 fn test_cp_parents_dest_not_directory() {
     new_ucmd!()
@@ -3398,6 +3568,26 @@ fn test_cp_fifo_preserve_timestamps() {
     assert!(at.is_fifo("pipe_dup"));
 }
 
+#[test]
+#[cfg(unix)]
+fn test_cp_archive_directory_with_fifo_and_socket() {
+    use std::os::unix::fs::FileTypeExt;
+    use std::os::unix::net::UnixListener;
+
+    // The FIFO has no writer: reading it would block the copy forever.
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.mkdir("src");
+    at.mkfifo("src/fifo");
+    at.write("src/file", "data");
+    let _listener = UnixListener::bind(at.plus("src/sock")).unwrap();
+
+    ucmd.args(&["-a", "src", "dest"]).succeeds().no_output();
+
+    assert!(at.is_fifo("dest/fifo"));
+    assert!(at.metadata("dest/sock").file_type().is_socket());
+    assert_eq!(at.read("dest/file"), "data");
+}
+
 #[rstest]
 #[case::recursive("-R")]
 #[case::archive("-a")]
@@ -5320,6 +5510,59 @@ fn test_cp_debug_default_zero_sized_virtual_file() {
         .stdout_contains("copy offload: unsupported, reflink: unsupported, sparse detection: no");
 }
 
+#[test]
+#[cfg(target_os = "linux")]
+fn test_cp_large_file_within_and_across_filesystems() {
+    use std::os::unix::fs::MetadataExt;
+
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+    let data: Vec<u8> = (0..5 * 1024 * 1024 + 123_u32)
+        .map(|i| (i * 7 + i / 251) as u8)
+        .collect();
+    at.write_bytes("large", &data);
+
+    ts.ucmd().args(&["large", "copy"]).succeeds().no_output();
+    assert_eq!(at.read_bytes("copy"), data);
+    ts.ucmd()
+        .args(&["--debug", "large", "copy2"])
+        .succeeds()
+        .stdout_contains("copy offload: yes");
+    assert_eq!(at.read_bytes("copy2"), data);
+
+    // A copy to another filesystem cannot be offloaded everywhere, and has
+    // to fall back to reading and writing.
+    let Ok(other_fs) = tempfile::TempDir::new_in("/dev/shm/") else {
+        return;
+    };
+    if other_fs.path().metadata().unwrap().dev() == at.metadata("large").dev() {
+        return;
+    }
+    let dest = other_fs.path().join("copy");
+    ts.ucmd().arg("large").arg(&dest).succeeds().no_output();
+    assert_eq!(std::fs::read(&dest).unwrap(), data);
+    // Whether the kernel offloads it depends on its version, but --debug
+    // reports what actually happened.
+    let dest = other_fs.path().join("copy2");
+    let result = ts.ucmd().arg("--debug").arg("large").arg(&dest).succeeds();
+    let stdout = result.stdout_str();
+    assert!(
+        stdout.contains("copy offload: yes") || stdout.contains("copy offload: unsupported"),
+        "{stdout}"
+    );
+    assert_eq!(std::fs::read(&dest).unwrap(), data);
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_cp_zero_sized_virtual_file_content() {
+    // procfs reports no data to `copy_file_range`, so this must not end up
+    // as an empty copy.
+    let (at, mut ucmd) = at_and_ucmd!();
+    ucmd.args(&["/proc/version", "b"]).succeeds().no_output();
+    assert_eq!(at.read_bytes("b"), std::fs::read("/proc/version").unwrap());
+}
+
 #[test]
 #[cfg(any(target_os = "linux", target_os = "android"))]
 fn test_cp_debug_reflink_never_without_hole() {
@@ -7141,6 +7384,38 @@ fn test_cp_preserve_selinux() {
     }
 }
 
+#[test]
+#[cfg(all(
+    feature = "feat_selinux",
+    any(target_os = "linux", target_os = "android")
+))]
+fn test_cp_preserve_context_matches_source() {
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+    at.write("plain", "content");
+
+    // Label a source file; skip where contexts can't be set.
+    if !ts
+        .ucmd()
+        .arg("--context=unconfined_u:object_r:user_tmp_t:s0")
+        .arg("plain")
+        .arg("labeled")
+        .run()
+        .succeeded()
+    {
+        println!("Skipping test: System doesn't support setting SELinux contexts");
+        return;
+    }
+
+    for (arg, copy) in [("--preserve=context", "copy1"), ("-a", "copy2")] {
+        ts.ucmd().arg(arg).arg("labeled").arg(copy).succeeds();
+        assert_eq!(
+            get_getfattr_output(&at.plus_as_string(copy)),
+            get_getfattr_output(&at.plus_as_string("labeled"))
+        );
+    }
+}
+
 #[test]
 #[cfg(all(
     feature = "feat_selinux",
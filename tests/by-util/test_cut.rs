@@ -209,6 +209,17 @@ fn test_zero_terminated() {
         .stdout_only("9\x007\0");
 }
 
+#[test]
+fn test_zero_terminated_output_delimiter() {
+    // The record terminator stays NUL while fields are joined with the
+    // output delimiter; embedded newlines are ordinary field content.
+    new_ucmd!()
+        .args(&["-z", "-d:", "-f1,3", "--output-delimiter=,"])
+        .pipe_in("a:b:c\0d\n:e:f\nx\0g")
+        .succeeds()
+        .stdout_only("a,c\0d\n,f\nx\0g\0");
+}
+
 #[test]
 fn test_only_delimited() {
     for param in ["-s", "--only-delimited", "--only-del"] {
@@ -220,6 +231,26 @@ fn test_only_delimited() {
     }
 }
 
+#[test]
+fn test_only_delimited_with_multichar_output_delimiter() {
+    new_ucmd!()
+        .args(&["-s", "-f2", "-d:", "--output-delimiter=--"])
+        .pipe_in("a:b:c\nnodelim\nx:\n")
+        .succeeds()
+        .stdout_only("b\n\n");
+    // A trailing delimiter yields an empty last field.
+    new_ucmd!()
+        .args(&["-s", "-f1-", "-d:", "--output-delimiter=--"])
+        .pipe_in("a:b:c\nnodelim\nx:\n")
+        .succeeds()
+        .stdout_only("a--b--c\nx--\n");
+    new_ucmd!()
+        .args(&["-s", "-f2,3", "-d:", "--output-delimiter=--"])
+        .pipe_in("a:b:c\nnodelim\nx:\n")
+        .succeeds()
+        .stdout_only("b--c\n\n");
+}
+
 #[test]
 fn test_zero_terminated_only_delimited() {
     new_ucmd!()
@@ -525,6 +556,36 @@ fn test_newline_as_delimiter_overlapping_unordered_ranges() {
         .stdout_only("a\nb\nc\n");
 }
 
+#[test]
+fn test_unordered_and_overlapping_ranges_are_merged() {
+    for (list, expected) in [("3-5,1,4", "a:c:d:e\n"), ("1-3,2-4", "a:b:c:d\n")] {
+        new_ucmd!()
+            .args(&["-d:", "-f", list])
+            .pipe_in("a:b:c:d:e:f\n")
+            .succeeds()
+            .stdout_only(expected);
+    }
+    new_ucmd!()
+        .args(&["-c", "4-,2,1-2"])
+        .pipe_in("abcdef\n")
+        .succeeds()
+        .stdout_only("abdef\n");
+}
+
+#[test]
+fn test_reversed_and_zero_ranges_are_rejected() {
+    new_ucmd!()
+        .args(&["-f", "5-3"])
+        .fails_with_code(1)
+        .no_stdout()
+        .stderr_contains("'5-3' was invalid: high end of range less than low end");
+    new_ucmd!()
+        .args(&["-b", "0-2"])
+        .fails_with_code(1)
+        .no_stdout()
+        .stderr_contains("'0-2' was invalid: fields and positions are numbered from 1");
+}
+
 #[test]
 fn test_newline_as_delimiter_complement_last_record() {
     // Test --complement on the final record
@@ -250,6 +250,26 @@ fn test_date_rfc_8601_date() {
     }
 }
 
+#[test]
+fn test_date_iso_short_option_precisions() {
+    for (arg, expected) in [
+        ("-I", "2023-11-15\n"),
+        ("-Idate", "2023-11-15\n"),
+        ("-Ihours", "2023-11-15T03+05:30\n"),
+        ("-Iminutes", "2023-11-15T03:43+05:30\n"),
+        ("-Iseconds", "2023-11-15T03:43:20+05:30\n"),
+        ("-Ins", "2023-11-15T03:43:20,000000000+05:30\n"),
+    ] {
+        new_ucmd!()
+            .env("TZ", "Asia/Kolkata")
+            .arg("-d")
+            .arg("@1700000000")
+            .arg(arg)
+            .succeeds()
+            .stdout_is(expected);
+    }
+}
+
 #[test]
 fn test_date_utc() {
     for param in ["--universal", "--utc", "--uct", "--uni", "--u"] {
@@ -2513,6 +2533,27 @@ fn test_date_cross_tz_mishandled() {
         .stdout_contains("1969");
 }
 
+#[test]
+fn test_date_embedded_tz_to_utc() {
+    // The embedded zone applies to the input only; -u selects UTC output
+    // whatever TZ says. The offset follows the date's DST state, and an
+    // ambiguous fall-back time resolves to the earlier (daylight) instant.
+    for (date, expected) in [
+        ("2024-07-01 12:00", "2024-07-01 16:00:00 UTC\n"),
+        ("2024-01-01 12:00", "2024-01-01 17:00:00 UTC\n"),
+        ("2024-11-03 01:30", "2024-11-03 05:30:00 UTC\n"),
+    ] {
+        new_ucmd!()
+            .env("TZ", "Asia/Tokyo")
+            .arg("-u")
+            .arg("-d")
+            .arg(format!(r#"TZ="America/New_York" {date}"#))
+            .arg("+%F %T %Z")
+            .succeeds()
+            .stdout_is(expected);
+    }
+}
+
 // Tests for GNU test invalid-high-bit-set: invalid UTF-8 in date string
 #[test]
 #[cfg(unix)]
@@ -780,6 +780,96 @@ fn test_partial_records_out() {
         .stderr_is("1+1 records in\n1+1 records out\n");
 }
 
+#[test]
+fn test_partial_records_mismatched_block_sizes() {
+    // Input records are counted in ibs units and output records in obs
+    // units, each with its own full+partial split.
+    for (args, input_len, expected) in [
+        (
+            ["ibs=1", "obs=512"],
+            1024,
+            "1024+0 records in\n2+0 records out\n",
+        ),
+        (
+            ["ibs=1", "obs=512"],
+            1000,
+            "1000+0 records in\n1+1 records out\n",
+        ),
+        (["ibs=3", "obs=4"], 10, "3+1 records in\n2+1 records out\n"),
+        (
+            ["ibs=512", "obs=3"],
+            10,
+            "0+1 records in\n3+1 records out\n",
+        ),
+    ] {
+        let input = "x".repeat(input_len);
+        new_ucmd!()
+            .args(&args)
+            .arg("status=noxfer")
+            .pipe_in(input.clone())
+            .succeeds()
+            .stdout_is(input)
+            .stderr_is(expected);
+    }
+}
+
+#[cfg(unix)]
+#[test]
+fn test_large_zero_count() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    ucmd.args(&[
+        "if=/dev/zero",
+        "of=zeros",
+        "bs=64K",
+        "count=1024",
+        "status=noxfer",
+    ])
+    .succeeds()
+    .no_stdout()
+    .stderr_is("1024+0 records in\n1024+0 records out\n");
+    assert_eq!(at.metadata("zeros").len(), 64 * 1024 * 1024);
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_noerror_sync_pads_unreadable_blocks() {
+    // Reading a directory fails with EISDIR, on every block.
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.mkdir("dir");
+    ucmd.args(&[
+        "if=dir",
+        "conv=noerror,sync",
+        "bs=4",
+        "count=2",
+        "status=noxfer",
+    ])
+    .succeeds()
+    .stdout_is_bytes([0; 8])
+    .stderr_is(
+        "dd: error reading 'dir': Is a directory\n\
+             0+0 records in\n0+0 records out\n\
+             dd: error reading 'dir': Is a directory\n\
+             0+1 records in\n1+0 records out\n\
+             0+2 records in\n2+0 records out\n",
+    );
+}
+
+#[test]
+fn test_noerror_status_none_is_silent() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.mkdir("dir");
+    ucmd.args(&[
+        "if=dir",
+        "conv=noerror,sync",
+        "bs=4",
+        "count=2",
+        "status=none",
+    ])
+    .succeeds()
+    .stdout_is_bytes([0; 8])
+    .no_stderr();
+}
+
 #[test]
 fn test_block_cbs16() {
     new_ucmd!()
@@ -1303,6 +1393,37 @@ fn test_bytes_oseek_seek_not_additive() {
         .stdout_is_fixture_bytes("dd-bytes-alphabet-null.spec");
 }
 
+#[test]
+fn test_iseek_and_oseek_files() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("in", "0123456789abcdef");
+    at.write("out", "ABCDEFGHIJKLMNOP");
+    ucmd.args(&["if=in", "of=out", "bs=2", "iseek=2", "oseek=3", "count=2"])
+        .args(&["conv=notrunc", "status=none"])
+        .succeeds()
+        .no_output();
+    assert_eq!(at.read("out"), "ABCDEF4567KLMNOP");
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_oseek_beyond_eof_leaves_hole() {
+    use std::os::unix::fs::MetadataExt;
+
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("in", "data");
+    ucmd.args(&["if=in", "of=out", "bs=1M", "oseek=4", "status=none"])
+        .succeeds()
+        .no_output();
+    let bytes = at.read_bytes("out");
+    assert_eq!(bytes.len(), 4 * 1024 * 1024 + 4);
+    assert!(bytes[..4 * 1024 * 1024].iter().all(|&b| b == 0));
+    assert_eq!(&bytes[4 * 1024 * 1024..], b"data");
+    // The skipped region is not written, so it takes up no blocks.
+    let meta = at.metadata("out");
+    assert!(meta.blocks() * 512 < meta.len());
+}
+
 #[test]
 fn test_final_stats_less_than_one_kb_si() {
     let result = new_ucmd!().pipe_in("0".repeat(999)).succeeds();
@@ -1343,6 +1464,24 @@ fn test_final_stats_three_char_limit() {
     );
 }
 
+#[test]
+fn test_final_stats_summary_line_format() {
+    let result = new_ucmd!()
+        .args(&["bs=1024", "count=2"])
+        .pipe_in("0".repeat(4096))
+        .succeeds();
+    let re = Regex::new(
+        r"^2\+0 records in\n2\+0 records out\n2048 bytes \(2\.0 kB, 2\.0 KiB\) copied, (\S+) s, \d+(\.\d)? [kMGTPEZYRQ]?B/s\n$",
+    )
+    .unwrap();
+    let caps = re
+        .captures(result.stderr_str())
+        .unwrap_or_else(|| panic!("unexpected summary: {:?}", result.stderr_str()));
+    // A sub-second copy still reports a positive, fractional duration.
+    let elapsed: f64 = caps[1].parse().unwrap();
+    assert!(elapsed > 0.0 && elapsed < 60.0);
+}
+
 #[test]
 fn test_invalid_number_arg_gnu_compatibility() {
     let commands = vec!["bs", "cbs", "count", "ibs", "obs", "seek", "skip"];
@@ -557,6 +557,47 @@ fn test_iuse_percentage() {
     }
 }
 
+#[test]
+#[cfg(target_os = "linux")]
+fn test_inodes_of_named_pseudo_filesystem() {
+    // A pseudo filesystem named on the command line is shown, with a dash
+    // for the percentage since it reports no inodes.
+    new_ucmd!()
+        .args(&["--output=source,itotal,iused,iavail,ipcent,target", "/proc"])
+        .succeeds()
+        .stdout_is(
+            "Filesystem     Inodes IUsed IFree IUse% Mounted on\n\
+             proc                0     0     0     - /proc\n",
+        );
+
+    let output = new_ucmd!()
+        .args(&["-i", "--total", "/", "/proc"])
+        .succeeds()
+        .stdout_str_lossy();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert_eq!(
+        lines[0].split_whitespace().collect::<Vec<_>>(),
+        [
+            "Filesystem",
+            "Inodes",
+            "IUsed",
+            "IFree",
+            "IUse%",
+            "Mounted",
+            "on"
+        ]
+    );
+    assert_eq!(
+        lines[2].split_whitespace().collect::<Vec<_>>(),
+        ["proc", "0", "0", "0", "-", "/proc"]
+    );
+    // The empty filesystem adds nothing to the totals.
+    let root: Vec<&str> = lines[1].split_whitespace().skip(1).take(4).collect();
+    let total: Vec<&str> = lines[3].split_whitespace().skip(1).take(4).collect();
+    assert_eq!(root, total);
+}
+
 #[test]
 fn test_default_block_size() {
     let output = new_ucmd!()
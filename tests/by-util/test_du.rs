@@ -983,6 +983,96 @@ fn test_du_h_locale_decimal_separator() {
     }
 }
 
+#[cfg(feature = "touch")]
+#[test]
+fn test_du_time_directory_reports_newest_in_subtree() {
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+
+    at.mkdir_all("d/sub");
+    at.touch("d/old");
+    at.touch("d/sub/new");
+    for (stamp, file) in [
+        ("202001010000", "d/old"),
+        ("202001010000", "d/sub"),
+        ("202001010000", "d"),
+        ("202305060708", "d/sub/new"),
+    ] {
+        ts.ccmd("touch")
+            .env("TZ", "UTC")
+            .args(&["-m", "-t", stamp, file])
+            .succeeds();
+    }
+
+    let times = |args: &[&str]| -> Vec<String> {
+        ts.ucmd()
+            .env("TZ", "UTC")
+            .args(args)
+            .succeeds()
+            .stdout_str()
+            .lines()
+            .map(|l| l.split('\t').skip(1).collect::<Vec<_>>().join("\t"))
+            .collect()
+    };
+
+    assert_eq!(
+        times(&["--time", "d"]),
+        ["2023-05-06 07:08\td/sub", "2023-05-06 07:08\td"]
+    );
+    assert_eq!(times(&["--time", "-sh", "d"]), ["2023-05-06 07:08\td"]);
+    assert_eq!(
+        times(&["--time", "-c", "d/old", "d"]),
+        [
+            "2020-01-01 00:00\td/old",
+            "2023-05-06 07:08\td/sub",
+            "2023-05-06 07:08\td",
+            "2023-05-06 07:08\ttotal"
+        ]
+    );
+    // With -S, subdirectories do not count towards their parent.
+    assert_eq!(
+        times(&["--time", "-S", "d"]),
+        ["2023-05-06 07:08\td/sub", "2020-01-01 00:00\td"]
+    );
+}
+
+#[cfg(feature = "touch")]
+#[test]
+fn test_du_separate_dirs_total_time() {
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+
+    at.mkdir_all("d/sub");
+    at.touch("d/old");
+    at.touch("d/sub/new");
+    for (stamp, file) in [
+        ("202001010000", "d/old"),
+        ("202001010000", "d/sub"),
+        ("202001010000", "d"),
+        ("202305060708", "d/sub/new"),
+    ] {
+        ts.ccmd("touch")
+            .env("TZ", "UTC")
+            .args(&["-m", "-t", stamp, file])
+            .succeeds();
+    }
+
+    // The total adds up every directory, including those not printed.
+    for args in [&["-S"][..], &["-S", "-s"]] {
+        let result = ts
+            .ucmd()
+            .env("TZ", "UTC")
+            .args(&["-c", "--time", "--inodes", "d"])
+            .args(args)
+            .succeeds();
+        assert!(
+            result
+                .stdout_str()
+                .ends_with("2\t2020-01-01 00:00\td\n4\t2023-05-06 07:08\ttotal\n")
+        );
+    }
+}
+
 #[allow(clippy::too_many_lines)]
 #[cfg(feature = "touch")]
 #[test]
@@ -1353,6 +1443,46 @@ fn test_du_bytes() {
     }
 }
 
+#[test]
+#[cfg(target_os = "linux")]
+fn test_du_sparse_file_apparent_size() {
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+    at.mkdir("d");
+    std::fs::File::create(at.plus("d/sparse"))
+        .expect("cannot create test file")
+        .set_len(10 * 1024 * 1024)
+        .expect("cannot set file size");
+    at.write("d/small", "abc");
+    // A second name for the same inode is only counted once.
+    at.hard_link("d/small", "d/link");
+
+    ts.ucmd()
+        .args(&["--apparent-size", "-a", "d/sparse"])
+        .succeeds()
+        .stdout_only("10240\td/sparse\n");
+    ts.ucmd()
+        .args(&["-b", "-a", "d/sparse"])
+        .succeeds()
+        .stdout_only("10485760\td/sparse\n");
+    ts.ucmd()
+        .args(&["-sb", "d"])
+        .succeeds()
+        .stdout_only("10485763\td\n");
+
+    let allocated: u64 = ts
+        .ucmd()
+        .args(&["-B1", "d/sparse"])
+        .succeeds()
+        .stdout_str()
+        .split('\t')
+        .next()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(allocated < 10 * 1024 * 1024);
+}
+
 #[test]
 fn test_du_exclude() {
     let ts = TestScenario::new(util_name!());
@@ -1924,6 +2054,42 @@ fn test_du_inodes_total_text() {
     assert!(parts[0].parse::<u64>().is_ok());
 }
 
+#[test]
+fn test_du_total_sums_directory_operands() {
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+
+    at.mkdir_all("a/x");
+    at.mkdir("b");
+    at.write("a/x/f", &"a".repeat(10_000));
+    at.write("b/g", &"b".repeat(30_000));
+
+    for args in [
+        &["-c", "a", "b"][..],
+        &["-cs", "a", "b"],
+        &["-c", "--inodes", "a", "b"],
+    ] {
+        let result = ts.ucmd().args(args).succeeds();
+        let sizes: Vec<(u64, &str)> = result
+            .stdout_str()
+            .lines()
+            .map(|line| {
+                let (size, name) = line.split_once('\t').unwrap();
+                (size.parse().unwrap(), name)
+            })
+            .collect();
+
+        let (total, name) = sizes.last().unwrap();
+        assert_eq!(*name, "total");
+        let operands: u64 = sizes
+            .iter()
+            .filter(|(_, name)| *name == "a" || *name == "b")
+            .map(|(size, _)| size)
+            .sum();
+        assert_eq!(*total, operands);
+    }
+}
+
 #[test]
 fn test_du_threshold_no_suggested_values() {
     // tested by tests/du/threshold
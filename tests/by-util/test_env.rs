@@ -834,6 +834,22 @@ fn test_env_overwrite_arg0() {
         .stderr_is("");
 }
 
+// The program is still looked up by its own name; argv0 only changes what
+// it sees as $0, even when that names a path that does not exist.
+#[test]
+#[cfg(unix)]
+fn test_env_argv0_does_not_affect_lookup() {
+    let ts = TestScenario::new(util_name!());
+
+    for argv0 in ["/nonexistent/prog", "", "with space"] {
+        ts.ucmd()
+            .args(&["-i", "-a", argv0, "PATH=/usr/bin:/bin"])
+            .args(&["sh", "-c", "echo \"[$0]\""])
+            .succeeds()
+            .stdout_only(format!("[{argv0}]\n"));
+    }
+}
+
 // Do not assume that coreutils uses argv0
 #[test]
 #[cfg(unix)]
@@ -1041,6 +1057,39 @@ fn test_env_block_signal_flag() {
         .no_stderr();
 }
 
+#[cfg(target_os = "linux")]
+fn child_signal_mask(args: &[&str], field: &str) -> u64 {
+    let result = new_ucmd!()
+        .env("PATH", PATH)
+        .args(args)
+        .args(&["cat", "/proc/self/status"])
+        .succeeds();
+    let line = result
+        .stdout_str()
+        .lines()
+        .find_map(|l| l.strip_prefix(field))
+        .unwrap()
+        .trim()
+        .to_owned();
+    u64::from_str_radix(&line, 16).unwrap()
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_env_signal_dispositions_reach_child() {
+    let bit = |sig: Signal| 1u64 << (sig as i32 - 1);
+    let int_term = bit(Signal::SIGINT) | bit(Signal::SIGTERM);
+
+    assert_eq!(
+        child_signal_mask(&["--block-signal=INT,15"], "SigBlk:") & int_term,
+        int_term
+    );
+    assert_eq!(
+        child_signal_mask(&["--ignore-signal=SIGINT,TERM"], "SigIgn:") & int_term,
+        int_term
+    );
+}
+
 #[test]
 #[cfg(any(target_os = "linux", target_os = "android"))]
 fn test_env_block_realtime_signal() {
@@ -70,6 +70,44 @@ fn test_simple_arithmetic() {
         .stdout_only("1\n");
 }
 
+#[test]
+fn test_arithmetic_beyond_64_bits() {
+    new_ucmd!()
+        .args(&["99999999999999999999", "+", "1"])
+        .succeeds()
+        .stdout_only("100000000000000000000\n");
+
+    new_ucmd!()
+        .args(&["18446744073709551616", "*", "18446744073709551616"])
+        .succeeds()
+        .stdout_only("340282366920938463463374607431768211456\n");
+
+    new_ucmd!()
+        .args(&["-9223372036854775808", "-", "1"])
+        .succeeds()
+        .stdout_only("-9223372036854775809\n");
+
+    new_ucmd!()
+        .args(&["100000000000000000000", "%", "7"])
+        .succeeds()
+        .stdout_only("2\n");
+
+    new_ucmd!()
+        .args(&["99999999999999999999", "<", "100000000000000000000"])
+        .succeeds()
+        .stdout_only("1\n");
+
+    new_ucmd!()
+        .args(&["100000000000000000000", "/", "0"])
+        .fails_with_code(2)
+        .stderr_only("expr: division by zero\n");
+
+    new_ucmd!()
+        .args(&["100000000000000000000", "+", "a"])
+        .fails_with_code(2)
+        .stderr_only("expr: non-integer argument\n");
+}
+
 #[test]
 fn test_complex_arithmetic() {
     new_ucmd!()
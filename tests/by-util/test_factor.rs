@@ -1700,3 +1700,12 @@ fn invalid_cmdline_arg_continues() {
         .stdout_is("4: 2 2\n")
         .stderr_is("factor: 'a' is not a valid positive integer\n");
 }
+
+#[test]
+fn invalid_stdin_token_continues() {
+    new_ucmd!()
+        .pipe_in("12 abc\n15\t7\n")
+        .fails_with_code(1)
+        .stdout_is("12: 2 2 3\n15: 3 5\n7: 7\n")
+        .stderr_is("factor: 'abc' is not a valid positive integer\n");
+}
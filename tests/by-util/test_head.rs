@@ -58,6 +58,28 @@ fn test_stdin_negative_23_line() {
         .stdout_is_fixture("lorem_ipsum_1_line.expected");
 }
 
+#[test]
+#[cfg(target_os = "linux")]
+fn test_bytes_from_endless_char_device() {
+    new_ucmd!()
+        .args(&["-c", "5", "/dev/zero"])
+        .succeeds()
+        .stdout_only("\0\0\0\0\0");
+}
+
+#[test]
+fn test_bytes_from_large_pipe() {
+    let input: String = (0..200_000)
+        .map(|i| char::from(b'a' + (i % 26) as u8))
+        .collect();
+    new_ucmd!()
+        .args(&["-c", "100003"])
+        .pipe_in(input.as_str())
+        .ignore_stdin_write_error()
+        .succeeds()
+        .stdout_only(&input[..100_003]);
+}
+
 #[test]
 fn test_stdin_5_chars() {
     new_ucmd!()
@@ -165,6 +187,53 @@ fn test_non_terminated_input() {
         .stdout_is("x\n");
 }
 
+#[test]
+fn test_zero_terminated_multiple_files_headers() {
+    // Records are NUL-separated, but like GNU the headers and the blank
+    // line between files still end with a newline.
+    let ts = TestScenario::new(util_name!());
+    ts.fixtures.write("a", "a1\0a2\0a3\0");
+    ts.fixtures.write("b", "b1\0b2\0");
+    ts.ucmd()
+        .args(&["-z", "-n", "2", "a", "b"])
+        .succeeds()
+        .stdout_only("==> a <==\na1\0a2\0\n==> b <==\nb1\0b2\0");
+}
+
+#[test]
+fn test_negative_lines_multiple_files() {
+    // Each file drops its own last lines; one shorter than the count is
+    // empty but keeps its header. Stdin goes through the non-seekable path.
+    let ts = TestScenario::new(util_name!());
+    ts.fixtures.write("long", "1\n2\n3\n4\n5\n");
+    ts.fixtures.write("short", "x\n");
+    ts.fixtures.write("unterminated", "p\nq\nr");
+    ts.ucmd()
+        .args(&["-n", "-2", "long", "short", "-", "unterminated"])
+        .pipe_in("s1\ns2\ns3\n")
+        .succeeds()
+        .stdout_only(concat!(
+            "==> long <==\n1\n2\n3\n",
+            "\n==> short <==\n",
+            "\n==> standard input <==\ns1\n",
+            "\n==> unterminated <==\np\n",
+        ));
+    ts.ucmd()
+        .args(&["-v", "-n", "-2", "short"])
+        .succeeds()
+        .stdout_only("==> short <==\n");
+}
+
+#[test]
+fn test_zero_terminated_verbose_single_file() {
+    let ts = TestScenario::new(util_name!());
+    ts.fixtures.write("a", "a1\0a2\0");
+    ts.ucmd()
+        .args(&["-z", "-v", "-n", "1", "a"])
+        .succeeds()
+        .stdout_only("==> a <==\na1\0");
+}
+
 #[test]
 fn test_zero_terminated_negative_lines() {
     new_ucmd!()
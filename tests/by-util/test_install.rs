@@ -472,6 +472,9 @@ fn test_install_preserve_timestamps() {
     let file1 = "source_file";
     let file2 = "target_file";
     at.touch(file1);
+    // Copying may update the source's access time, so compare with the
+    // times from before the copy.
+    let file1_accessed = at.metadata(file1).accessed().ok();
 
     ucmd.arg(file1).arg(file2).arg("-p").succeeds().no_stderr();
 
@@ -481,10 +484,7 @@ fn test_install_preserve_timestamps() {
     let file1_metadata = at.metadata(file1);
     let file2_metadata = at.metadata(file2);
 
-    assert_eq!(
-        file1_metadata.accessed().ok(),
-        file2_metadata.accessed().ok()
-    );
+    assert_eq!(file1_accessed, file2_metadata.accessed().ok());
     assert_eq!(
         file1_metadata.modified().ok(),
         file2_metadata.modified().ok()
@@ -518,6 +518,43 @@ fn test_install_compare_preserve_timestamps() {
     );
 }
 
+#[test]
+fn test_install_preserve_timestamps_nanoseconds_with_strip() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    // A fake strip program that rewrites the installed file, updating its times.
+    at.write("fake-strip", "#!/bin/sh\nprintf stripped > \"$1\"\n");
+    at.set_mode("fake-strip", 0o755);
+    at.write("src", "original");
+    let atime = FileTime::from_unix_time(1_000_000, 123_456_789);
+    let mtime = FileTime::from_unix_time(2_000_000, 987_654_321);
+
+    for (args, dest, content) in [
+        (&["-p"][..], "plain", "original"),
+        (
+            &["-p", "-s", "--strip-program=./fake-strip"],
+            "stripped",
+            "stripped",
+        ),
+    ] {
+        // Reading the source may update its access time, so reset it each time.
+        filetime::set_file_times(at.plus("src"), atime, mtime).unwrap();
+        scene
+            .ucmd()
+            .args(args)
+            .arg("src")
+            .arg(dest)
+            .succeeds()
+            .no_stderr();
+
+        let meta = at.metadata(dest);
+        assert_eq!(at.read(dest), content);
+        assert_eq!(FileTime::from_last_modification_time(&meta), mtime);
+        assert_eq!(FileTime::from_last_access_time(&meta), atime);
+    }
+}
+
 // These two tests are failing but should work
 #[test]
 fn test_install_copy_file() {
@@ -1047,6 +1084,42 @@ fn test_install_creating_leading_dirs() {
     assert!(at.file_exists(target));
 }
 
+#[test]
+#[cfg(unix)]
+fn test_install_creating_leading_dirs_uses_directory_mode() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    let source = "create_leading_test_file";
+    let target = "a/b/c/d/test_file";
+
+    at.touch(source);
+
+    scene
+        .ucmd()
+        .args(&["-D", "-m", "600", source, target])
+        .succeeds()
+        .no_stderr();
+
+    assert_eq!(at.metadata(target).permissions().mode() & 0o7777, 0o600);
+    for dir in ["a", "a/b", "a/b/c", "a/b/c/d"] {
+        let mode = at.metadata(dir).permissions().mode();
+        assert!(at.dir_exists(dir));
+        // The parents get the default directory mode, not the file mode.
+        assert_eq!(mode & 0o700, 0o700, "unexpected mode {mode:o} for {dir}");
+    }
+
+    // Installing again with all parents present is a no-op for them.
+    at.write(source, "updated");
+    scene
+        .ucmd()
+        .args(&["-Dv", source, target])
+        .succeeds()
+        .stdout_does_not_contain("creating directory")
+        .no_stderr();
+    assert_eq!(at.read(target), "updated");
+}
+
 #[test]
 fn test_install_creating_leading_dirs_verbose() {
     let scene = TestScenario::new(util_name!());
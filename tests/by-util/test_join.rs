@@ -79,6 +79,37 @@ fn only_whitespace_separators_merge() {
         .stdout_only(" a  ,,,b,c \n");
 }
 
+#[test]
+fn tab_separator_and_default_blank_runs() {
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+    at.write("tab_1", "a\tx y\nb\tz\n");
+    at.write("tab_2", "a\t1\nb\t\t2\n");
+    at.write("blank_1", "a   x\n  b  z\n");
+    at.write("blank_2", "a 1\nb    2\n");
+    at.write("line_1", "a x\nb y\n");
+    at.write("line_2", "a x\nb z\n");
+
+    // With -t the separator is kept on output and empty fields survive.
+    ts.ucmd()
+        .args(&["-t", "\t", "tab_1", "tab_2"])
+        .succeeds()
+        .stdout_only("a\tx y\t1\nb\tz\t\t2\n");
+
+    // Without -t, leading blanks are skipped, runs of blanks separate
+    // fields, and output fields are joined by a single space.
+    ts.ucmd()
+        .args(&["blank_1", "blank_2"])
+        .succeeds()
+        .stdout_only("a x 1\nb z 2\n");
+
+    // An empty -t makes the whole line the join field.
+    ts.ucmd()
+        .args(&["-t", "", "line_1", "line_2"])
+        .succeeds()
+        .stdout_only("a x\n");
+}
+
 #[test]
 fn different_fields() {
     new_ucmd!()
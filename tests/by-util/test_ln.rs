@@ -914,7 +914,7 @@ fn test_hard_logical_dir_fail() {
         .ucmd()
         .args(&["-L", target, "hard-to-dir-link"])
         .fails()
-        .stderr_contains("failed to create hard link 'link-to-dir'");
+        .stderr_contains("failed to create hard link 'hard-to-dir-link' => 'link-to-dir'");
 }
 
 #[test]
@@ -1086,6 +1086,32 @@ fn test_ln_hard_link_dir() {
         .stderr_contains("hard link not allowed for directory");
 }
 
+#[test]
+#[cfg(target_os = "linux")]
+fn test_ln_directory_hard_link_dir() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    at.mkdir("dir");
+
+    // Linux refuses directory hard links even for the superuser, so the
+    // EPERM from link() is reported.
+    for opt in ["-d", "-F", "--directory"] {
+        scene
+            .ucmd()
+            .args(&[opt, "dir", "dir_link"])
+            .fails_with_code(1)
+            .stderr_only(
+                "ln: failed to create hard link 'dir_link' => 'dir': Operation not permitted\n",
+            );
+        assert!(!at.file_exists("dir_link"));
+    }
+
+    at.touch("file");
+    scene.ucmd().args(&["-d", "file", "file_link"]).succeeds();
+    assert!(at.file_exists("file_link"));
+}
+
 #[test]
 fn test_ln_backup_no_path_traversal() {
     let scene = TestScenario::new(util_name!());
@@ -478,6 +478,29 @@ fn test_ls_allocation_size() {
     }
 }
 
+#[test]
+#[cfg(all(unix, not(target_os = "freebsd"), not(target_os = "openbsd")))]
+fn test_ls_allocation_size_total_sums_blocks() {
+    use std::os::unix::fs::MetadataExt;
+
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.mkdir("d");
+    at.write("d/data", &"x".repeat(10_000));
+    at.make_file("d/sparse").set_len(8 * 1024 * 1024).unwrap();
+
+    // The leading column and the total count allocated 1K blocks, so the
+    // sparse file contributes far less than its 8 MiB length.
+    let blocks = |name: &str| at.metadata(&format!("d/{name}")).blocks() / 2;
+    let (data, sparse) = (blocks("data"), blocks("sparse"));
+    assert!(sparse < 8 * 1024);
+
+    ucmd.args(&["-sl", "d"])
+        .succeeds()
+        .stdout_matches(&Regex::new(&format!("^total {}\n", data + sparse)).unwrap())
+        .stdout_matches(&Regex::new(&format!("(?m)^ *{data} .* data$")).unwrap())
+        .stdout_matches(&Regex::new(&format!("(?m)^ *{sparse} .* 8388608 .* sparse$")).unwrap());
+}
+
 #[test]
 fn test_ls_devices() {
     let scene = TestScenario::new(util_name!());
@@ -1914,6 +1937,65 @@ fn test_ls_long_formats() {
     }
 }
 
+#[test]
+fn test_ls_one_per_line_when_not_a_tty() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.touch("a");
+    at.touch("b");
+    at.touch("c");
+
+    scene.ucmd().succeeds().stdout_only("a\nb\nc\n");
+    scene.ucmd().arg("-C").succeeds().stdout_only("a  b  c\n");
+    scene.ucmd().arg("-x").succeeds().stdout_only("a  b  c\n");
+
+    #[cfg(unix)]
+    {
+        scene
+            .ucmd()
+            .terminal_simulation(true)
+            .succeeds()
+            .stdout_only("a  b  c\r\n");
+        scene
+            .ucmd()
+            .arg("-1")
+            .terminal_simulation(true)
+            .succeeds()
+            .stdout_only("a\r\nb\r\nc\r\n");
+    }
+}
+
+#[test]
+#[cfg(target_os = "linux")]
+fn test_ls_long_author_alignment() {
+    use std::os::unix::fs::chown;
+
+    if !rustix::process::geteuid().is_root() {
+        return;
+    }
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.touch("a");
+    at.touch("b");
+    chown(at.plus("a"), Some(424_242), Some(424_242)).unwrap();
+    chown(at.plus("b"), Some(0), Some(0)).unwrap();
+
+    // Names are left-aligned, while IDs without a name are right-aligned.
+    scene
+        .ucmd()
+        .args(&["-l", "--author", "--time-style=+T", "a", "b"])
+        .succeeds()
+        .stdout_contains(" 1 424242 424242 424242 0 T a\n")
+        .stdout_contains(" 1 root   root   root   0 T b\n");
+
+    scene
+        .ucmd()
+        .args(&["-ln", "--author", "--time-style=+T", "a", "b"])
+        .succeeds()
+        .stdout_contains(" 1 424242 424242 424242 0 T a\n")
+        .stdout_contains(" 1      0      0      0 0 T b\n");
+}
+
 #[test]
 fn test_ls_oneline() {
     let scene = TestScenario::new(util_name!());
@@ -2070,6 +2152,49 @@ fn test_ls_sort_none() {
     scene.ucmd().arg("-U").succeeds();
 }
 
+#[test]
+fn test_ls_sort_none_ignores_reverse() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    // Larger than any directory, so that "dir" sorts as the smallest entry.
+    for (name, size) in [("b.txt", 8000), ("a", 6000), ("c.rs", 7000), ("d", 5000)] {
+        at.write_bytes(name, &vec![b'x'; size]);
+    }
+    at.mkdir("dir");
+
+    let unsorted = scene
+        .ucmd()
+        .args(&["-1", "-U"])
+        .succeeds()
+        .stdout_move_str();
+    let mut names: Vec<&str> = unsorted.lines().collect();
+    names.sort_unstable();
+    assert_eq!(names, ["a", "b.txt", "c.rs", "d", "dir"]);
+
+    for args in [
+        &["-1", "-U", "-r"][..],
+        &["-1", "--sort=none", "-r"],
+        &["-1", "-U", "--group-directories-first"],
+    ] {
+        scene.ucmd().args(args).succeeds().stdout_is(&unsorted);
+    }
+
+    // The other sorts still honor both options, and put files without an
+    // extension first under -X.
+    for (args, expected) in [
+        (&["-1", "-S", "-r"][..], "dir\nd\na\nc.rs\nb.txt\n"),
+        (&["-1", "-X"], "a\nd\ndir\nc.rs\nb.txt\n"),
+        (&["-1", "-X", "-r"], "b.txt\nc.rs\ndir\nd\na\n"),
+        (
+            &["-1", "-X", "--group-directories-first"],
+            "dir\na\nd\nc.rs\nb.txt\n",
+        ),
+    ] {
+        scene.ucmd().args(args).succeeds().stdout_is(expected);
+    }
+}
+
 #[test]
 fn test_ls_sort_name() {
     let scene = TestScenario::new(util_name!());
@@ -3629,6 +3754,35 @@ fn test_ls_indicator_style_slash_symlink_target_long() {
         .stdout_does_not_contain("-> dir/");
 }
 
+#[test]
+#[cfg(unix)]
+fn test_ls_indicator_per_file_type() {
+    use std::os::unix::fs::symlink;
+    use std::os::unix::net::UnixListener;
+
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    at.mkdir("d");
+    at.touch("f");
+    at.touch("x");
+    at.set_mode("x", 0o755);
+    symlink("d", at.plus("ld")).unwrap();
+    symlink("f", at.plus("lf")).unwrap();
+    at.mkfifo("p");
+    let _listener = UnixListener::bind(at.plus("s")).unwrap();
+
+    for (args, expected) in [
+        (&["-F"][..], "d/\nf\nld@\nlf@\np|\ns=\nx*\n"),
+        (&["-p"], "d/\nf\nld\nlf\np\ns\nx\n"),
+        (&["-F", "-L"], "d/\nf\nld/\nlf\np|\ns=\nx*\n"),
+        // Standard output is not a terminal here.
+        (&["--classify=auto"], "d\nf\nld\nlf\np\ns\nx\n"),
+    ] {
+        scene.ucmd().args(args).succeeds().stdout_only(expected);
+    }
+}
+
 // Essentially the same test as above, but only test symlinks and directories,
 // not pipes or sockets.
 #[test]
@@ -4626,6 +4780,50 @@ fn test_ls_deref_command_line() {
         .stdout_contains("sym_file ->");
 }
 
+#[test]
+fn test_ls_deref_operand_vs_contents_indicators() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    at.mkdir("d");
+    at.write("d/f", "hello\n");
+    at.relative_symlink_file("f", "d/lf");
+    at.symlink_file("nowhere", "broken");
+
+    scene
+        .ucmd()
+        .args(&["-F", "d/lf"])
+        .succeeds()
+        .stdout_is("d/lf@\n");
+    for opt in ["-H", "-L"] {
+        scene
+            .ucmd()
+            .args(&[opt, "-F", "d/lf"])
+            .succeeds()
+            .stdout_is("d/lf\n");
+        let result = scene.ucmd().args(&[opt, "-l", "d/lf"]).succeeds();
+        assert!(result.stdout_str().starts_with('-'));
+        assert!(result.stdout_str().contains(" 6 "));
+        scene
+            .ucmd()
+            .args(&[opt, "-F", "broken"])
+            .fails_with_code(2)
+            .stderr_is("ls: cannot access 'broken': No such file or directory\n");
+    }
+
+    // -H only follows operands; -L also follows directory contents.
+    scene
+        .ucmd()
+        .args(&["-H", "-F", "d"])
+        .succeeds()
+        .stdout_is("f\nlf@\n");
+    scene
+        .ucmd()
+        .args(&["-L", "-F", "d"])
+        .succeeds()
+        .stdout_is("f\nlf\n");
+}
+
 #[test]
 fn test_ls_deref_command_line_dir() {
     let scene = TestScenario::new(util_name!());
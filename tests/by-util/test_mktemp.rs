@@ -609,6 +609,19 @@ fn test_directory_permissions() {
     assert_eq!(metadata.permissions().mode(), 0o40700);
 }
 
+#[test]
+fn test_dry_run_creates_nothing() {
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+
+    for args in [&["-u", "XXX"][..], &["-u", "-d", "XXX"]] {
+        let result = scene.ucmd().args(args).succeeds();
+        let name = result.no_stderr().stdout_str().trim_end();
+        assert_matches_template!("XXX", name);
+        assert!(!at.plus(name).exists());
+    }
+}
+
 /// Test that a template with a path separator is invalid.
 #[test]
 fn test_template_path_separator() {
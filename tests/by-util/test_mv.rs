@@ -1285,6 +1285,40 @@ fn test_mv_arg_update_older_dest_older() {
     assert_eq!(at.read(old), new_content);
 }
 
+#[test]
+fn test_mv_arg_update_older_replaces_older_dest() {
+    let (at, mut ucmd) = at_and_ucmd!();
+
+    at.write("dest", "dest content\n");
+    filetime::set_file_mtime(at.plus("dest"), FileTime::zero()).unwrap();
+    at.write("src", "src content\n");
+
+    ucmd.args(&["--update=older", "src", "dest"])
+        .succeeds()
+        .no_output();
+
+    assert!(!at.file_exists("src"));
+    assert_eq!(at.read("dest"), "src content\n");
+}
+
+#[test]
+fn test_mv_arg_update_older_equal_mtime_skips() {
+    let (at, mut ucmd) = at_and_ucmd!();
+
+    at.write("src", "src content\n");
+    at.write("dest", "dest content\n");
+    let mtime = FileTime::from_unix_time(1_000_000, 0);
+    filetime::set_file_mtime(at.plus("src"), mtime).unwrap();
+    filetime::set_file_mtime(at.plus("dest"), mtime).unwrap();
+
+    ucmd.args(&["--update=older", "src", "dest"])
+        .succeeds()
+        .no_output();
+
+    assert!(at.file_exists("src"));
+    assert_eq!(at.read("dest"), "dest content\n");
+}
+
 #[test]
 fn test_mv_arg_update_older_dest_older_interactive() {
     let (at, mut ucmd) = at_and_ucmd!();
@@ -2720,6 +2754,45 @@ fn test_mv_selinux_context() {
     }
 }
 
+#[test]
+#[cfg(all(
+    feature = "feat_selinux",
+    any(target_os = "linux", target_os = "android")
+))]
+fn test_mv_selinux_default_context_of_destination() {
+    use uucore::selinux::{
+        get_selinux_security_context, is_selinux_enabled, set_selinux_security_context,
+    };
+
+    if !is_selinux_enabled() {
+        println!("Skipping SELinux test: SELinux is not enabled");
+        return;
+    }
+    let scene = TestScenario::new(util_name!());
+    let at = &scene.fixtures;
+    at.mkdir("src_dir");
+    at.mkdir("dest_dir");
+    at.touch("src_dir/file");
+    at.touch("dest_dir/reference");
+    set_selinux_security_context(
+        &at.plus("src_dir/file"),
+        Some(&"unconfined_u:object_r:user_tmp_t:s0".to_string()),
+    )
+    .unwrap();
+    set_selinux_security_context(&at.plus("dest_dir/reference"), None).unwrap();
+
+    scene
+        .ucmd()
+        .args(&["-Z", "src_dir/file", "dest_dir/file"])
+        .succeeds()
+        .no_output();
+
+    assert_eq!(
+        get_selinux_security_context(&at.plus("dest_dir/file"), false).unwrap(),
+        get_selinux_security_context(&at.plus("dest_dir/reference"), false).unwrap()
+    );
+}
+
 #[test]
 fn test_mv_error_usage_display_missing_arg() {
     new_ucmd!()
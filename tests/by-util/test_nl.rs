@@ -153,6 +153,33 @@ fn test_number_format_rz_with_negative_line_number() {
     }
 }
 
+#[test]
+fn test_number_format_with_width_and_separator() {
+    let input = "a\nb\nc\nd\ne\nf\ng\n";
+    for (format, expected) in [
+        ("ln", "7     |g\n"),
+        ("rn", "     7|g\n"),
+        ("rz", "000007|g\n"),
+    ] {
+        let result = new_ucmd!()
+            .args(&["-n", format, "-w", "6", "-s", "|"])
+            .pipe_in(input)
+            .succeeds();
+        assert!(result.stdout_str().ends_with(expected));
+    }
+}
+
+#[test]
+fn test_number_wider_than_width_is_not_truncated() {
+    for format in ["ln", "rn", "rz"] {
+        new_ucmd!()
+            .args(&["-n", format, "-w", "2", "-v", "120"])
+            .pipe_in("a\n")
+            .succeeds()
+            .stdout_is("120\ta\n");
+    }
+}
+
 #[test]
 fn test_invalid_number_format() {
     for arg in ["-ninvalid", "--number-format=invalid"] {
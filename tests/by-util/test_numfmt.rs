@@ -161,6 +161,16 @@ fn test_header_default() {
         .stdout_is("header\n1000\n1100000\n100000000");
 }
 
+#[test]
+fn test_header_with_field_is_verbatim() {
+    // The header is passed through untouched even where it holds numbers.
+    new_ucmd!()
+        .args(&["--header", "--field=2", "--to=iec"])
+        .pipe_in("name   4096  size\na 1024 x\nb 2048 y\n")
+        .succeeds()
+        .stdout_is("name   4096  size\na 1.0K x\nb 2.0K y\n");
+}
+
 #[test]
 fn test_header_error_if_non_numeric() {
     new_ucmd!()
@@ -758,6 +768,27 @@ fn test_transform_with_suffix_and_delimiter() {
         .stdout_only("1.0kb|2000|3000");
 }
 
+#[test]
+fn test_suffix_round_trips_throughput() {
+    new_ucmd!()
+        .args(&["--suffix=B/s", "--to=si", "--field=2"])
+        .pipe_in("eth0 1500000 rx\neth1 2000000000 tx\n")
+        .succeeds()
+        .stdout_only("eth0 1.5MB/s rx\neth1    2.0GB/s tx\n");
+
+    new_ucmd!()
+        .args(&["--suffix=B/s", "--from=si", "--field=2"])
+        .pipe_in("eth0 1.5MB/s rx\neth1 2.0GB/s tx\n")
+        .succeeds()
+        .stdout_only("eth0 1500000B/s rx\neth1 2000000000B/s tx\n");
+
+    new_ucmd!()
+        .args(&["--suffix=B/s"])
+        .pipe_in("12B/sfoo")
+        .fails_with_code(2)
+        .stderr_is("numfmt: invalid suffix in input: '12B/sfoo'\n");
+}
+
 #[test]
 fn test_suffix_with_padding() {
     new_ucmd!()
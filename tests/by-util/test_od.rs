@@ -141,6 +141,54 @@ fn test_multiple_formats() {
         ));
 }
 
+#[test]
+fn test_char_escapes() {
+    // Known control characters use C escapes, others 3-digit octal.
+    new_ucmd!()
+        .arg("-c")
+        .run_piped_stdin(&b"a\tb\nc\x01\xff\r"[..])
+        .success()
+        .stdout_only(unindent(
+            r"
+            0000000   a  \t   b  \n   c 001 377  \r
+            0000010
+            ",
+        ));
+}
+
+#[test]
+fn test_stacked_format_shortcuts() {
+    let input = b"a\tb\nc\x01\xff\r";
+    new_ucmd!()
+        .arg("--endian=little")
+        .arg("-x")
+        .arg("-c")
+        .run_piped_stdin(&input[..])
+        .success()
+        .stdout_only(unindent(
+            r"
+            0000000    0961    0a62    0163    0dff
+                      a  \t   b  \n   c 001 377  \r
+            0000010
+            ",
+        ));
+    new_ucmd!()
+        .arg("--endian=little")
+        .arg("-a")
+        .arg("-d")
+        .arg("-o")
+        .run_piped_stdin(&input[..])
+        .success()
+        .stdout_only(unindent(
+            "
+            0000000   a  ht   b  nl   c soh del  cr
+                       2401    2658     355    3583
+                     004541  005142  000543  006777
+            0000010
+            ",
+        ));
+}
+
 #[test]
 fn test_dec() {
     // spell-checker:ignore (words) 0xffu8 xffu
@@ -249,6 +249,21 @@ A ALPHA 1 _B BRAVO 2 _C CHARLIE 3 _
     }
 }
 
+// Each row restarts the delimiter list; within a row the list is cycled
+// between successive columns.
+#[test]
+fn test_delimiter_list_cycles_across_files() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("f1", "1\n2\n");
+    at.write("f2", "a\nb\n");
+    at.write("f3", "x\ny\n");
+    at.write("f4", "p\nq\n");
+
+    ucmd.args(&["-d", ",;", "f1", "f2", "f3", "f4"])
+        .succeeds()
+        .stdout_only("1,a;x,p\n2,b;y,q\n");
+}
+
 // Was panicking (usize subtraction that would have resulted in a negative number)
 // Not observable in release builds, since integer overflow checking is not enabled
 #[test]
@@ -127,6 +127,27 @@ fn sub_b_string_variable_size_unicode() {
         .stdout_only_bytes([b'|', 0, b'5', b'|']);
 }
 
+#[test]
+fn sub_b_string_octal_escapes() {
+    new_ucmd!()
+        .args(&["%b|%b\n", "\\0123\\101", "a\\\\b\\vc"])
+        .succeeds()
+        .stdout_only("SA|a\\b\x0bc\n");
+}
+
+#[test]
+fn sub_b_string_backslash_c_stops_all_output() {
+    new_ucmd!()
+        .args(&["%b|%s\n", "x\\cyz", "never"])
+        .succeeds()
+        .stdout_only("x");
+    // Nor is the format reused for the remaining arguments.
+    new_ucmd!()
+        .args(&["%b|", "a", "b\\c", "c"])
+        .succeeds()
+        .stdout_only("a|b");
+}
+
 #[test]
 fn sub_b_string_validate_field_params() {
     new_ucmd!()
@@ -269,6 +290,14 @@ fn sub_num_int_char_const_in() {
         .stdout_only("emoji is 128579");
 }
 
+#[test]
+fn sub_num_dec_char_const_and_hex_in() {
+    new_ucmd!()
+        .args(&["%d %d %d %d\n", "'A", "\"A", "'é", "0x1F"])
+        .succeeds()
+        .stdout_only("65 65 233 31\n");
+}
+
 #[test]
 fn sub_num_thousands() {
     // For "C" locale, the thousands separator is ignored but should
@@ -1466,6 +1495,19 @@ fn positional_format_specifiers() {
         .stdout_only("Octal: 115, Int: 42, Float: 3.141590, String: hello, Hex: ff, Scientific: 1.000000e-05, Char: A, Unsigned: 100, Integer: 123");
 }
 
+#[test]
+fn positional_reordering_and_width() {
+    new_ucmd!()
+        .args(&["%2$s %1$s\n", "a", "b"])
+        .succeeds()
+        .stdout_only("b a\n");
+
+    new_ucmd!()
+        .args(&["[%1$*2$d] [%1$-*2$d]\n", "42", "6"])
+        .succeeds()
+        .stdout_only("[    42] [42    ]\n");
+}
+
 #[test]
 #[cfg(target_family = "unix")]
 #[cfg_attr(wasi_runner, ignore = "WASI: argv/filenames must be valid UTF-8")]
@@ -420,6 +420,23 @@ fn test_verbose_slash() {
     assert!(!at.file_exists(file_a));
 }
 
+#[test]
+#[cfg(unix)]
+fn test_verbose_recursive_post_order() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    // One entry per directory keeps the listing independent of readdir order.
+    at.mkdir_all("tree/sub/deep");
+    at.touch("tree/sub/deep/file");
+
+    ucmd.args(&["-rv", "tree"]).succeeds().stdout_only(
+        "removed 'tree/sub/deep/file'\n\
+         removed directory 'tree/sub/deep'\n\
+         removed directory 'tree/sub'\n\
+         removed directory 'tree'\n",
+    );
+    assert!(!at.dir_exists("tree"));
+}
+
 #[test]
 fn test_silently_accepts_presume_input_tty2() {
     let (at, mut ucmd) = at_and_ucmd!();
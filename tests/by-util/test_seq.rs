@@ -110,6 +110,33 @@ fn test_hex_rejects_sign_after_identifier() {
         .usage_error("invalid floating point argument: '-0x+123ABC'");
 }
 
+#[test]
+fn test_hex_operands() {
+    new_ucmd!()
+        .arg("0x10")
+        .succeeds()
+        .stdout_is("1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n11\n12\n13\n14\n15\n16\n");
+    new_ucmd!()
+        .args(&["0x1", "0x2", "0xA"])
+        .succeeds()
+        .stdout_is("1\n3\n5\n7\n9\n");
+    // Like GNU, the format only takes floating point directives.
+    new_ucmd!()
+        .args(&["-f", "%x", "1", "16"])
+        .fails_with_code(1)
+        .no_stdout();
+}
+
+#[test]
+fn test_descending_bounds_with_implicit_step() {
+    new_ucmd!().args(&["5", "1"]).succeeds().no_output();
+    new_ucmd!().args(&["--", "-1", "-3"]).succeeds().no_output();
+    new_ucmd!()
+        .args(&["--", "-3", "-1"])
+        .succeeds()
+        .stdout_is("-3\n-2\n-1\n");
+}
+
 #[test]
 fn test_hex_lowercase_uppercase() {
     new_ucmd!()
@@ -361,6 +388,25 @@ fn test_zero_step() {
     new_ucmd!().args(&["10", "0", "32"]).fails();
 }
 
+#[test]
+fn test_zero_step_spellings() {
+    for step in ["0", "0.0", "-0", "0e5"] {
+        new_ucmd!()
+            .args(&["1", step, "5"])
+            .fails_with_code(1)
+            .no_stdout()
+            .stderr_contains(format!("seq: invalid Zero increment value: '{step}'\n"));
+    }
+}
+
+#[test]
+fn test_tiny_nonzero_step() {
+    new_ucmd!()
+        .args(&["1", "0.001", "1.003"])
+        .succeeds()
+        .stdout_only("1.000\n1.001\n1.002\n1.003\n");
+}
+
 #[test]
 fn test_big_numbers() {
     new_ucmd!()
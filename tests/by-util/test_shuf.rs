@@ -948,6 +948,23 @@ fn test_head_count_does_not_overflow_range() {
         .no_stderr();
 }
 
+#[test]
+fn test_head_count_samples_large_pipe() {
+    let mut input = String::new();
+    for n in 0..1_000_000 {
+        writeln!(&mut input, "{n}").unwrap();
+    }
+    let result = new_ucmd!().arg("-n2").pipe_in(input.as_bytes()).succeeds();
+    let lines: Vec<u32> = result
+        .stdout_str()
+        .lines()
+        .map(|line| line.parse().unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_ne!(lines[0], lines[1]);
+    assert!(lines.iter().all(|&n| n < 1_000_000));
+}
+
 // Test reproducibility and compatibility of --random-source.
 // These hard-coded results match those of GNU shuf. They should not be changed.
 
@@ -1035,10 +1052,32 @@ fn test_gnu_compat_limited_from_file() {
         .stdout_is("7\n1\n2\n5\n3\n");
 }
 
-// This specific case causes GNU to give different results than other modes.
-#[ignore = "disabled until fixed"]
+// With --head-count, input of unknown size is reservoir sampled, which
+// consumes the random source differently from the other modes.
+#[test]
+fn test_gnu_compat_limited_from_pipe() {
+    for (count, expected) in [
+        ("-n7", "6\n5\n1\n3\n2\n7\n4\n"),
+        ("-n3", "5\n1\n4\n"),
+        // Input shorter than the reservoir is shuffled as a whole.
+        ("-n10", "7\n1\n2\n5\n3\n4\n6\n"),
+    ] {
+        let (at, mut ucmd) = at_and_ucmd!();
+        at.append_bytes(
+            "random_bytes.bin",
+            b"\xd1\xfd\xb9\x9a\xf5\x81\x71\x42\xf9\x7a\x59\x79\xd4\x9c\x8c\x7d",
+        );
+        ucmd.arg("--random-source=random_bytes.bin")
+            .arg(count)
+            .pipe_in("1\n2\n3\n4\n5\n6\n7\n")
+            .succeeds()
+            .no_stderr()
+            .stdout_is(expected);
+    }
+}
+
 #[test]
-fn test_gnu_compat_limited_from_stdin() {
+fn test_gnu_compat_limited_from_stdin_file() {
     let (at, mut ucmd) = at_and_ucmd!();
     at.append_bytes(
         "random_bytes.bin",
@@ -1052,7 +1091,7 @@ fn test_gnu_compat_limited_from_stdin() {
         .set_stdin(at.open("input.txt"))
         .succeeds()
         .no_stderr()
-        .stdout_is("6\n5\n1\n3\n2\n7\n4\n");
+        .stdout_is("7\n1\n2\n5\n3\n4\n6\n");
 }
 
 #[test]
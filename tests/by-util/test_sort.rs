@@ -377,6 +377,48 @@ fn test_random_shuffle_two_runs_not_the_same() {
     }
 }
 
+#[test]
+fn test_random_source_is_reproducible() {
+    let ts = TestScenario::new("sort");
+    ts.fixtures.write("seed", "fixed random source bytes");
+    let input = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n";
+    let first = ts
+        .ucmd()
+        .args(&["-R", "--random-source=seed"])
+        .pipe_in(input)
+        .succeeds()
+        .stdout_move_str();
+    assert_ne!(first, input);
+    for arg in ["-R", "-k1,1R"] {
+        ts.ucmd()
+            .args(&[arg, "--random-source=seed"])
+            .pipe_in(input)
+            .succeeds()
+            .stdout_is(&first);
+    }
+}
+
+#[test]
+fn test_random_equal_keys_stay_grouped() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("seed", "another fixed source");
+    let input = "a 1\nb 1\nc 2\nd 3\ne 2\nf 1\ng 3\nh 2\n";
+    let output = ucmd
+        .args(&["-k2,2R", "--random-source=seed"])
+        .pipe_in(input)
+        .succeeds()
+        .stdout_move_str();
+
+    let keys: Vec<&str> = output
+        .lines()
+        .map(|line| line.split(' ').nth(1).unwrap())
+        .collect();
+    assert_eq!(keys.len(), 8);
+    let mut groups = keys.clone();
+    groups.dedup();
+    assert_eq!(groups.len(), 3, "equal keys are not contiguous: {keys:?}");
+}
+
 #[test]
 fn test_random_ignore_case() {
     let input = "ABC\nABc\nAbC\nAbc\naBC\naBc\nabC\nabc\n";
@@ -970,6 +1012,24 @@ fn test_keys_with_options() {
     }
 }
 
+#[test]
+fn test_keys_with_mixed_per_key_options() {
+    // Each key honors only its own modifiers: key 2 folds case, key 3 is
+    // numeric and reversed.
+    new_ucmd!()
+        .args(&["-k2,2f", "-k3,3nr"])
+        .pipe_in("x B 10\nx a 9\nx A 2\nx b 1\nx a 10\n")
+        .succeeds()
+        .stdout_only("x a 10\nx a 9\nx A 2\nx B 10\nx b 1\n");
+
+    // Dictionary order on key 1, general numeric on key 2.
+    new_ucmd!()
+        .args(&["-k1,1d", "-k2,2g"])
+        .pipe_in("a.b 1e2\nab 5\n.ab 2e1\nb 0\n")
+        .succeeds()
+        .stdout_only("ab 5\n.ab 2e1\na.b 1e2\nb 0\n");
+}
+
 #[test]
 fn test_keys_with_options_blanks_start() {
     let input = "aa   3 cc\ndd  1 ff\ngg         2 cc\n";
@@ -1237,6 +1297,27 @@ fn test_check_silent() {
     }
 }
 
+#[test]
+fn test_unique_compares_keys() {
+    // The first line of each run of equal keys survives.
+    new_ucmd!()
+        .args(&["-k1,1", "-u"])
+        .pipe_in("a 2\nb 1\na 3\nb 0\nc 9\n")
+        .succeeds()
+        .stdout_only("a 2\nb 1\nc 9\n");
+    new_ucmd!()
+        .args(&["-u", "-k2,2"])
+        .pipe_in("b\na\nb\n")
+        .succeeds()
+        .stdout_only("b\n");
+    // Without -k the whole line is the key.
+    new_ucmd!()
+        .arg("-u")
+        .pipe_in("a 2\nb 1\na 2\na 3\n")
+        .succeeds()
+        .stdout_only("a 2\na 3\nb 1\n");
+}
+
 #[test]
 fn test_check_unique() {
     new_ucmd!()
@@ -1945,6 +2026,20 @@ fn test_g_float() {
         .stdout_is(output);
 }
 
+#[test]
+fn test_g_scientific_inf_and_nan() {
+    // Non-numbers come first, then NaNs (unsigned before negative), then
+    // numbers in order, including ones too large for an f64.
+    new_ucmd!()
+        .env("LC_ALL", "C")
+        .arg("-g")
+        .pipe_in("1e3\n20\n-inf\n  7\n-nan\n+inf\nnan\nx\n1e400\n99999999999999999999999\n+nan\n")
+        .succeeds()
+        .stdout_is(
+            "x\n+nan\nnan\n-nan\n-inf\n  7\n20\n1e3\n99999999999999999999999\n1e400\n+inf\n",
+        );
+}
+
 #[test]
 fn test_g_float_locale_decimal_separator() {
     let Ok(locale_fr_utf8) = env::var("LOCALE_FR_UTF8") else {
@@ -2930,11 +3025,9 @@ fn test_locale_posix_byte_order() {
 
 #[test]
 fn test_locale_with_ignore_case_flag() {
-    // When -f (ignore case) is used, the comparison uses custom_str_cmp
-    // which converts to uppercase for comparison. With -f flag, all letters
-    // are treated as equivalent regardless of case, so original order is preserved
-    // for equal keys (stable sort behavior within equal elements).
-    // Note: This may differ slightly from GNU in tie-breaking behavior.
+    // When -f (ignore case) is used, keys are folded to uppercase before they
+    // are compared, so all letters are equivalent regardless of case and ties
+    // are broken by comparing the whole lines.
     let result = new_ucmd!()
         .env("LC_ALL", "en_US.UTF-8")
         .arg("-f")
@@ -2966,6 +3059,29 @@ fn test_locale_with_ignore_case_flag() {
     );
 }
 
+#[test]
+fn test_locale_collation_with_fold_and_dictionary_order() {
+    // -f and -d filter and fold the keys, then collate them like the default
+    // mode does. The C locale keeps plain byte order.
+    for (locale, folded, dictionary) in [
+        ("en_US.UTF-8", "b\nB\ne\nE\né\nf\n", "a\nA\nb-x\nB.y\n"),
+        ("C", "B\nb\nE\ne\nf\né\n", "A\nB.y\na\nb-x\n"),
+    ] {
+        new_ucmd!()
+            .env("LC_ALL", locale)
+            .arg("-f")
+            .pipe_in("é\nE\ne\nf\nb\nB\n")
+            .succeeds()
+            .stdout_is(folded);
+        new_ucmd!()
+            .env("LC_ALL", locale)
+            .arg("-d")
+            .pipe_in("b-x\nB.y\na\nA\n")
+            .succeeds()
+            .stdout_is(dictionary);
+    }
+}
+
 #[test]
 fn test_locale_complex_utf8_sorting() {
     // More complex test with mixed case and special characters
@@ -1227,10 +1227,8 @@ fn test_elide_empty_files_l_chunks() {
     assert_eq!(at.read("xaa"), "1\n");
     assert_eq!(at.read("xab"), "2\n");
     assert_eq!(at.read("xac"), "3\n");
-    assert_eq!(at.read("xad"), "4\n");
-    assert_eq!(at.read("xae"), "5\n");
-    assert!(!at.plus("xaf").exists());
-    assert!(!at.plus("xag").exists());
+    assert_eq!(at.read("xad"), "4\n5\n");
+    assert!(!at.plus("xae").exists());
 }
 
 #[test]
@@ -1267,6 +1265,46 @@ fn test_number_by_lines() {
     assert_eq!(at.read("xab"), "4\n5\n");
 }
 
+#[test]
+fn test_number_by_lines_boundaries() {
+    // 22 bytes in 3 chunks of 7 bytes: each chunk ends with the line that
+    // reaches its last byte, and the last chunk takes the rest.
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("in", "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\nk\n");
+    ucmd.args(&["-n", "l/3", "in"]).succeeds().no_output();
+    assert_eq!(at.read("xaa"), "a\nb\nc\nd\n");
+    assert_eq!(at.read("xab"), "e\nf\ng\n");
+    assert_eq!(at.read("xac"), "h\ni\nj\nk\n");
+
+    // A line spanning several chunks leaves the chunks it covers empty.
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("in", "ab\ncd");
+    ucmd.args(&["-n", "l/3", "in"]).succeeds().no_output();
+    assert_eq!(at.read("xaa"), "ab\n");
+    assert_eq!(at.read("xab"), "");
+    assert_eq!(at.read("xac"), "cd");
+
+    // A single line longer than a chunk ends up entirely in the first one.
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("in", &"x".repeat(100));
+    ucmd.args(&["-n", "l/3", "in"]).succeeds().no_output();
+    assert_eq!(at.read("xaa"), "x".repeat(100));
+    assert_eq!(at.read("xab"), "");
+    assert_eq!(at.read("xac"), "");
+
+    // Inputs shorter than the number of chunks still use one-byte chunks.
+    new_ucmd!()
+        .args(&["-n", "l/2/3"])
+        .pipe_in("x")
+        .succeeds()
+        .no_output();
+    new_ucmd!()
+        .args(&["-n", "l/4/7"])
+        .pipe_in("ab\ncd")
+        .succeeds()
+        .stdout_only("cd");
+}
+
 #[test]
 fn test_number_by_lines_kth() {
     new_ucmd!()
@@ -1786,8 +1824,8 @@ fn test_split_separator_nl_number_l() {
         .succeeds();
 
     assert_eq!(at.read("xaa"), "1\n2\n");
-    assert_eq!(at.read("xab"), "3\n4\n");
-    assert_eq!(at.read("xac"), "5\n");
+    assert_eq!(at.read("xab"), "3\n");
+    assert_eq!(at.read("xac"), "4\n5\n");
     assert!(!at.plus("xad").exists());
 }
 
@@ -1834,8 +1872,8 @@ fn test_split_separator_nul_number_l() {
         .succeeds();
 
     assert_eq!(at.read("xaa"), "1\x002\0");
-    assert_eq!(at.read("xab"), "3\x004\0");
-    assert_eq!(at.read("xac"), "5\0");
+    assert_eq!(at.read("xab"), "3\0");
+    assert_eq!(at.read("xac"), "4\x005\0");
     assert!(!at.plus("xad").exists());
 }
 
@@ -1882,8 +1920,8 @@ fn test_split_separator_semicolon_number_l() {
         .succeeds();
 
     assert_eq!(at.read("xaa"), "1;2;");
-    assert_eq!(at.read("xab"), "3;4;");
-    assert_eq!(at.read("xac"), "5;");
+    assert_eq!(at.read("xab"), "3;");
+    assert_eq!(at.read("xac"), "4;5;");
     assert!(!at.plus("xad").exists());
 }
 
@@ -142,6 +142,91 @@ fn test_normal_format() {
     ts.ucmd().args(&args).succeeds().stdout_is(expected_stdout);
 }
 
+#[cfg(unix)]
+#[test]
+fn test_mode_special_bits() {
+    use std::fs::{Permissions, set_permissions};
+    use std::os::unix::fs::PermissionsExt;
+
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.touch("setuid");
+    at.touch("setgid");
+    at.mkdir("sticky");
+    at.mkdir("sticky_no_x");
+    for (name, mode) in [
+        ("setuid", 0o4755),
+        ("setgid", 0o2745),
+        ("sticky", 0o1777),
+        ("sticky_no_x", 0o1776),
+    ] {
+        set_permissions(at.plus(name), Permissions::from_mode(mode)).unwrap();
+    }
+
+    ucmd.args(&[
+        "-c",
+        "%a %A %n",
+        "setuid",
+        "setgid",
+        "sticky",
+        "sticky_no_x",
+    ])
+    .succeeds()
+    .stdout_is(concat!(
+        "4755 -rwsr-xr-x setuid\n",
+        "2745 -rwxr-Sr-x setgid\n",
+        "1777 drwxrwxrwt sticky\n",
+        "1776 drwxrwxrwT sticky_no_x\n",
+    ));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_printf_flags_and_width() {
+    use std::fs::{Permissions, set_permissions};
+    use std::os::unix::fs::PermissionsExt;
+
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("f", "12345");
+    set_permissions(at.plus("f"), Permissions::from_mode(0o644)).unwrap();
+
+    ucmd.args(&[
+        "-c",
+        "[%#a] [%#6a] [%05a] [%8s] [%-8s] [%08s] [%+s] [%-10n] [%4n]",
+        "f",
+    ])
+    .succeeds()
+    .stdout_is(
+        "[0644] [  0644] [00644] [       5] [5       ] [00000005] [+5] [f         ] [   f]\n",
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn test_cached_modes() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.touch("f");
+    at.symlink_file("f", "link");
+    ucmd.args(&["--cached=never", "-c", "%n %F", "link"])
+        .succeeds()
+        .stdout_is("link symbolic link\n");
+    for mode in ["always", "default", "al"] {
+        new_ucmd!()
+            .current_dir(at.as_string())
+            .args(&["--cached", mode, "-c", "%s", "f"])
+            .succeeds()
+            .stdout_is("0\n");
+    }
+    new_ucmd!()
+        .current_dir(at.as_string())
+        .args(&["--cached=never", "missing"])
+        .fails_with_code(1)
+        .stderr_contains("cannot statx 'missing': No such file or directory");
+    new_ucmd!()
+        .args(&["--cached=sometimes", "."])
+        .fails_with_code(1)
+        .stderr_contains("invalid value 'sometimes'");
+}
+
 #[cfg(unix)]
 #[cfg(not(target_os = "openbsd"))]
 #[test]
@@ -107,6 +107,25 @@ fn test_no_line_separators() {
     new_ucmd!().pipe_in("a").succeeds().stdout_is("a");
 }
 
+#[test]
+fn test_trailing_separator_present_or_missing() {
+    // Like GNU, an unterminated last record is emitted first, as is, and so
+    // runs into the record that follows it.
+    for (args, input, expected) in [
+        (&[][..], "a\nb\n", "b\na\n"),
+        (&[][..], "a\nb", "ba\n"),
+        (&["-s", ":"][..], "a:b:", "b:a:"),
+        (&["-s", ":"][..], "a:b", "ba:"),
+        (&["-s", ":"][..], "a", "a"),
+    ] {
+        new_ucmd!()
+            .args(args)
+            .pipe_in(input)
+            .succeeds()
+            .stdout_is(expected);
+    }
+}
+
 #[test]
 fn test_before_trailing_separator_no_leading_separator() {
     new_ucmd!()
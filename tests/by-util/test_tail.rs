@@ -248,6 +248,31 @@ fn test_n0_with_follow() {
     child.kill();
 }
 
+#[test]
+#[cfg(unix)]
+fn test_lines_without_follow_ignores_appended_data() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    let test_file = "grow.txt";
+    let mut content = String::new();
+    for i in 0..100_000 {
+        content.push_str(&format!("line {i}\n"));
+    }
+    at.write(test_file, &content);
+
+    // The output is far larger than a pipe buffer, so `tail` is still busy
+    // writing when the data is appended.
+    let mut child = ucmd
+        .args(&["-n", "100000", test_file])
+        .set_stdout(Stdio::piped())
+        .run_no_wait();
+    let mut output = child.stdout_exact_bytes(16);
+    at.append(test_file, "appended\n");
+    output.extend(child.stdout_bytes());
+
+    assert_eq!(String::from_utf8(output).unwrap(), content);
+    child.wait().unwrap().success();
+}
+
 // TODO: Add similar test for windows
 #[test]
 #[cfg(unix)]
@@ -849,6 +874,25 @@ fn test_bytes_stdin() {
         .no_stderr();
 }
 
+#[test]
+fn test_bytes_from_large_pipe() {
+    let input: String = (0..200_000)
+        .map(|i| char::from(b'a' + (i % 26) as u8))
+        .collect();
+    let expected = &input[input.len() - 70_001..];
+    new_ucmd!()
+        .args(&["-c", "70001"])
+        .pipe_in(input.as_str())
+        .succeeds()
+        .stdout_only(expected);
+    #[cfg(unix)]
+    new_ucmd!()
+        .args(&["-c", "70001", "/dev/stdin"])
+        .pipe_in(input.as_str())
+        .succeeds()
+        .stdout_only(expected);
+}
+
 #[test]
 fn test_bytes_big() {
     const FILE: &str = "test_bytes_big.txt";
@@ -1072,6 +1116,68 @@ fn test_sleep_interval() {
         .stderr_contains("invalid number of seconds: '1..1'");
 }
 
+#[test]
+#[cfg(not(target_os = "windows"))] // FIXME: for currently not working platforms
+fn test_follow_sleep_interval_picks_up_updates() {
+    // With a short interval, appended data must show up within a couple of
+    // polling iterations rather than after the default one second.
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+    at.write("f", "a\n");
+
+    for interval in ["-s.1", "-s0"] {
+        let mut p = ts
+            .ucmd()
+            .args(&[interval, "-f", "f", "---disable-inotify"])
+            .run_no_wait();
+        p.make_assertion_with_delay(300).is_alive();
+
+        at.append("f", "b\n");
+        p.delay(300);
+
+        p.make_assertion().is_alive();
+        p.kill()
+            .make_assertion()
+            .with_all_output()
+            .stdout_only("a\nb\n");
+
+        at.write("f", "a\n");
+    }
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))] // FIXME: for currently not working platforms
+fn test_follow_name_max_unchanged_stats_reopens_rotated_file() {
+    // After `--max-unchanged-stats` quiet iterations the name is re-checked
+    // and a rotated file is reopened.
+    let ts = TestScenario::new(util_name!());
+    let at = &ts.fixtures;
+    at.write("log", "old\n");
+
+    let mut p = ts
+        .ucmd()
+        .args(&[
+            "-s.1",
+            "--max-unchanged-stats=1",
+            "-F",
+            "log",
+            "---disable-inotify",
+        ])
+        .run_no_wait();
+    p.make_assertion_with_delay(300).is_alive();
+
+    at.rename("log", "log.1");
+    at.write("log", "new\n");
+    p.delay(500);
+
+    p.make_assertion().is_alive();
+    p.kill()
+        .make_assertion()
+        .with_all_output()
+        .stdout_is("old\nnew\n")
+        .stderr_contains("'log' has been replaced;  following new file");
+}
+
 /// Test for reading all but the first NUM bytes: `tail -c +3`.
 #[test]
 fn test_positive_bytes() {
@@ -124,6 +124,34 @@ fn test_tee_multiple_append_flags() {
     assert_eq!(at.read(file2), format!("existing2\n{content}"));
 }
 
+#[test]
+fn test_tee_append_creates_missing_file() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    let content = "tee_sample_content";
+
+    ucmd.args(&["-a", "new_file"])
+        .pipe_in(content)
+        .succeeds()
+        .stdout_is(content);
+    assert_eq!(at.read("new_file"), content);
+}
+
+#[test]
+#[cfg(unix)]
+fn test_directory_target_does_not_stop_other_outputs() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    let content = "tee_sample_content";
+    at.mkdir("dir");
+    at.write("file", "existing\n");
+
+    ucmd.args(&["-a", "dir", "file"])
+        .pipe_in(content)
+        .fails_with_code(1)
+        .stdout_is(content)
+        .stderr_is("tee: dir: Is a directory\n");
+    assert_eq!(at.read("file"), format!("existing\n{content}"));
+}
+
 #[test]
 #[cfg_attr(wasi_runner, ignore = "WASI sandbox: host paths not visible")]
 fn test_readonly() {
@@ -105,6 +105,31 @@ fn test_delete_complement_graph_and_print_match_gnu() {
         .stdout_is_bytes(*b" A!");
 }
 
+#[test]
+fn test_delete_complement_print_and_literals() {
+    let mut input: Vec<u8> = (0..=255).collect();
+    input.extend_from_slice(b"ok\n");
+    let printable: Vec<u8> = (b' '..=b'~').collect();
+
+    let mut expected = vec![b'\n'];
+    expected.extend_from_slice(&printable);
+    expected.extend_from_slice(b"ok\n");
+    new_ucmd!()
+        .args(&["-cd", "[:print:]\\n"])
+        .pipe_in(input.clone())
+        .succeeds()
+        .stdout_is_bytes(&expected);
+
+    let mut expected = b"\t\n".to_vec();
+    expected.extend_from_slice(&printable);
+    expected.extend_from_slice(b"ok\n");
+    new_ucmd!()
+        .args(&["-cd", "[:print:]\n\t"])
+        .pipe_in(input)
+        .succeeds()
+        .stdout_is_bytes(&expected);
+}
+
 #[test]
 fn test_delete_afterwards_is_not_flag() {
     new_ucmd!()
@@ -317,6 +342,21 @@ fn test_delete_and_squeeze() {
         .stdout_is("B");
 }
 
+#[test]
+fn test_delete_and_squeeze_spaces() {
+    new_ucmd!()
+        .args(&["-ds", "a-z", " "])
+        .pipe_in("Hello  World  aab    x\n")
+        .succeeds()
+        .stdout_is("H W \n");
+    // A character in both sets is deleted, not squeezed.
+    new_ucmd!()
+        .args(&["-ds", "ab", "ab "])
+        .pipe_in("xaab  bbaay")
+        .succeeds()
+        .stdout_is("x y");
+}
+
 #[test]
 fn test_delete_and_squeeze_complement() {
     new_ucmd!()
@@ -1594,6 +1634,32 @@ fn test_backwards_range() {
         );
 }
 
+#[test]
+fn test_repeat_constructs_in_set2() {
+    new_ucmd!()
+        .args(&["ab", "[x*]"])
+        .pipe_in("abcde")
+        .succeeds()
+        .stdout_is("xxcde");
+    new_ucmd!()
+        .args(&["abcde", "[y*3]z"])
+        .pipe_in("abcde")
+        .succeeds()
+        .stdout_is("yyyzz");
+    // [c*] fills whatever SET1 length is left after the other SET2 items.
+    new_ucmd!()
+        .args(&["a-g", "q[y*]z"])
+        .pipe_in("abcdefg")
+        .succeeds()
+        .stdout_is("qyyyyyz");
+    // A count with a leading zero is octal.
+    new_ucmd!()
+        .args(&["a-j", "[y*010]z"])
+        .pipe_in("abcdefghij")
+        .succeeds()
+        .stdout_is("yyyyyyyyzz");
+}
+
 #[test]
 fn test_non_digit_repeat() {
     new_ucmd!()
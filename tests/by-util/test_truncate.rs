@@ -370,6 +370,34 @@ fn test_new_file_no_create_size_and_reference() {
     assert!(!at.file_exists(filename));
 }
 
+/// Test that a relative size is applied to each of several operands.
+#[test]
+fn test_multiple_files_relative_size() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write(FILE1, "12345");
+    at.write(FILE2, "1");
+    ucmd.args(&["-s", "+2", FILE1, FILE2, "new_file"])
+        .succeeds()
+        .no_output();
+    assert_eq!(at.read_bytes(FILE1), b"12345\0\0");
+    assert_eq!(at.read_bytes(FILE2), b"1\0\0");
+    assert_eq!(at.read_bytes("new_file"), b"\0\0");
+}
+
+/// Test that `-c` only truncates the operands that already exist.
+#[test]
+fn test_multiple_files_no_create() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write(FILE1, "12345");
+    at.write(FILE2, "67890");
+    ucmd.args(&["-c", "-s", "2", FILE1, "missing", FILE2])
+        .succeeds()
+        .no_output();
+    assert_eq!(at.read(FILE1), "12");
+    assert_eq!(at.read(FILE2), "67");
+    assert!(!at.file_exists("missing"));
+}
+
 #[test]
 fn test_division_by_zero_size_only() {
     new_ucmd!()
@@ -239,6 +239,23 @@ fn test_stdin_ignore_case() {
         .stdout_is_fixture("sorted-ignore-case.expected");
 }
 
+#[test]
+fn test_stdin_skip_fields_then_chars_then_check_chars() {
+    // Skip one field, then two chars (the blank and the letter), then compare "abc".
+    // Lines too short for the skips have an empty key and compare equal.
+    let input = "1 Xabc1\n2 Yabc2\n3 zABC3\n4 Wab\n5\n6\n";
+    new_ucmd!()
+        .args(&["-f1", "-s2", "-w3", "-c"])
+        .pipe_in(input)
+        .succeeds()
+        .stdout_is("      2 1 Xabc1\n      1 3 zABC3\n      1 4 Wab\n      2 5\n");
+    new_ucmd!()
+        .args(&["-f1", "-s2", "-w3", "-c", "-i"])
+        .pipe_in(input)
+        .succeeds()
+        .stdout_is("      3 1 Xabc1\n      1 4 Wab\n      2 5\n");
+}
+
 #[test]
 fn test_stdin_zero_terminated() {
     new_ucmd!()
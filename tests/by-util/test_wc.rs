@@ -429,6 +429,40 @@ fn test_file_bytes_dictate_width() {
         .stdout_is(STDOUT);
 }
 
+/// Columns always follow the fixed order lines, words, chars, bytes,
+/// max-line-length, whatever order the flags were given in.
+#[test]
+fn test_column_order_ignores_flag_order() {
+    let expected = concat!(
+        "   5   57 alice_in_wonderland.txt\n",
+        "  13  109 lorem_ipsum.txt\n",
+        "  18  166 total\n",
+    );
+    for args in [["-wl"], ["-lw"]] {
+        new_ucmd!()
+            .args(&args)
+            .args(&["alice_in_wonderland.txt", "lorem_ipsum.txt"])
+            .succeeds()
+            .stdout_is(expected);
+    }
+    new_ucmd!()
+        .args(&["-w", "-l", "alice_in_wonderland.txt", "lorem_ipsum.txt"])
+        .succeeds()
+        .stdout_is(expected);
+}
+
+/// Like GNU, `-c` and `-m` may be combined: chars come before bytes.
+#[test]
+fn test_bytes_and_chars_together() {
+    for args in [["-c", "-m"], ["-m", "-c"]] {
+        new_ucmd!()
+            .args(&args)
+            .pipe_in("h\u{e9}llo\n")
+            .succeeds()
+            .stdout_is("      6       7\n");
+    }
+}
+
 /// Test that getting counts from a directory is an error.
 #[test]
 fn test_read_from_directory_error() {
@@ -705,6 +739,19 @@ fn test_zero_length_files() {
         ));
 }
 
+#[test]
+fn test_files0_from_mixed_missing_files_total() {
+    let (at, mut ucmd) = at_and_ucmd!();
+    at.write("one", "a b\n");
+    at.write("two", "x\ny\n");
+    at.write("list", "one\0missing\0two\0");
+
+    ucmd.args(&["--files0-from=list", "--total=always"])
+        .fails_with_code(1)
+        .stderr_is("wc: missing: No such file or directory\n")
+        .stdout_is("1 2 4 one\n2 2 4 two\n3 4 8 total\n");
+}
+
 #[test]
 fn test_files0_errors_quoting() {
     // Column padding differs on WASI (different terminal width detection)
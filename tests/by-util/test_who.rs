@@ -292,3 +292,65 @@ fn test_piped_to_dev_full() {
         .fails()
         .stderr_is("who: No space left on device\n");
 }
+
+/// Build a glibc `struct utmp` `USER_PROCESS` entry logged in at 1700000000.
+#[cfg(target_os = "linux")]
+fn utmp_user_record(user: &str, line: &str, pid: i32, host: &str) -> Vec<u8> {
+    fn field(rec: &mut Vec<u8>, value: &str, len: usize) {
+        assert!(value.len() < len);
+        rec.extend_from_slice(value.as_bytes());
+        rec.resize(rec.len() + len - value.len(), 0);
+    }
+    let mut rec = Vec::with_capacity(384);
+    rec.extend_from_slice(&7i16.to_ne_bytes()); // ut_type = USER_PROCESS
+    rec.extend_from_slice(&[0, 0]);
+    rec.extend_from_slice(&pid.to_ne_bytes());
+    field(&mut rec, line, 32);
+    field(&mut rec, "x", 4);
+    field(&mut rec, user, 32);
+    field(&mut rec, host, 256);
+    rec.extend_from_slice(&[0; 8]); // ut_exit, ut_session
+    rec.extend_from_slice(&1_700_000_000i32.to_ne_bytes());
+    rec.resize(384, 0);
+    rec
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn test_users_idle_and_pid_from_file() {
+    use filetime::FileTime;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let (at, mut ucmd) = uutests::at_and_ucmd!();
+    // ut_line is relative to /dev, so reach the fixtures through "..".
+    let old_tty = format!("..{}/tty1", at.as_string());
+    let recent_tty = format!("..{}/tty2", at.as_string());
+    if recent_tty.len() >= 32 {
+        return;
+    }
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+    at.touch("tty1");
+    at.touch("tty2");
+    filetime::set_file_atime(
+        at.plus("tty1"),
+        FileTime::from_unix_time(now - 3 * 24 * 3600, 0),
+    )
+    .unwrap();
+    filetime::set_file_atime(at.plus("tty2"), FileTime::from_unix_time(now - 7200, 0)).unwrap();
+    let mut utmp = utmp_user_record("alice", &old_tty, 4242, "example.org");
+    utmp.extend(utmp_user_record("bob", &recent_tty, 77, ""));
+    at.write_bytes("utmp", &utmp);
+
+    ucmd.args(&["-u", "-H", "utmp"])
+        .env("LC_ALL", "C")
+        .env("TZ", "UTC")
+        .succeeds()
+        .stdout_only(format!(
+            "NAME     LINE         TIME         IDLE          PID COMMENT\n\
+             alice    {old_tty:<12} Nov 14 22:13  old         4242 (example.org)\n\
+             bob      {recent_tty:<12} Nov 14 22:13 02:00          77\n"
+        ));
+}